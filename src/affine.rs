@@ -0,0 +1,140 @@
+use crate::point::Point;
+
+/// A 2D affine transformation, stored as the coefficients `[a, b, xoff, d, e, yoff]`
+/// of
+///
+/// ```text
+/// x' = a * x + b * y + xoff
+/// y' = d * x + e * y + yoff
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Affine([f64; 6]);
+
+impl Affine {
+    /// Construct a new `Affine` from its six coefficients.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::affine::Affine;
+    ///
+    /// let affine = Affine::new(1., 0., 2., 0., 1., 3.);
+    ///
+    /// assert_eq!(affine.apply([1., 1.]), [3., 4.]);
+    /// ```
+    pub fn new(a: f64, b: f64, xoff: f64, d: f64, e: f64, yoff: f64) -> Self {
+        Affine([a, b, xoff, d, e, yoff])
+    }
+
+    /// The `Affine` that leaves every coordinate unchanged.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::affine::Affine;
+    ///
+    /// assert_eq!(Affine::identity().apply([1., 2.]), [1., 2.]);
+    /// ```
+    pub fn identity() -> Self {
+        Affine::new(1., 0., 0., 0., 1., 0.)
+    }
+
+    /// The `Affine` that moves every coordinate by `dx` along the x-axis and `dy` along
+    /// the y-axis.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::affine::Affine;
+    ///
+    /// assert_eq!(Affine::translation(1., -1.).apply([1., 2.]), [2., 1.]);
+    /// ```
+    pub fn translation(dx: f64, dy: f64) -> Self {
+        Affine::new(1., 0., dx, 0., 1., dy)
+    }
+
+    /// The `Affine` that scales every coordinate by `sx` along the x-axis and `sy` along
+    /// the y-axis, relative to `origin`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::affine::Affine;
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// assert_eq!(Affine::scale(2., 3., Point::ORIGIN).apply([2., 2.]), [4., 6.]);
+    /// assert_eq!(Affine::scale(2., 3., Point::new(1, 1)).apply([2., 2.]), [3., 4.]);
+    /// ```
+    pub fn scale(sx: f64, sy: f64, origin: Point) -> Self {
+        Affine::new(
+            sx,
+            0.,
+            origin.x() - sx * origin.x(),
+            0.,
+            sy,
+            origin.y() - sy * origin.y(),
+        )
+    }
+
+    /// The `Affine` that rotates every coordinate counter-clockwise by `degrees` around
+    /// `origin`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::affine::Affine;
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// let [x, y] = Affine::rotation(90., Point::ORIGIN).apply([1., 0.]);
+    ///
+    /// assert!(x.abs() < 1e-12);
+    /// assert!((y - 1.).abs() < 1e-12);
+    ///
+    /// let [x, y] = Affine::rotation(90., Point::new(1, 0)).apply([2., 0.]);
+    ///
+    /// assert!((x - 1.).abs() < 1e-12);
+    /// assert!((y - 1.).abs() < 1e-12);
+    /// ```
+    pub fn rotation(degrees: f64, origin: Point) -> Self {
+        let (sin, cos) = degrees.to_radians().sin_cos();
+        Affine::new(
+            cos,
+            -sin,
+            origin.x() - cos * origin.x() + sin * origin.y(),
+            sin,
+            cos,
+            origin.y() - sin * origin.x() - cos * origin.y(),
+        )
+    }
+
+    /// Return the `Affine` that applies this transformation followed by `other`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::affine::Affine;
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// let affine = Affine::translation(1., 0.).then(&Affine::scale(2., 2., Point::ORIGIN));
+    ///
+    /// assert_eq!(affine.apply([1., 1.]), [4., 2.]);
+    /// ```
+    pub fn then(&self, other: &Affine) -> Affine {
+        let [a, b, xoff, d, e, yoff] = self.0;
+        let [oa, ob, oxoff, od, oe, oyoff] = other.0;
+        Affine::new(
+            oa * a + ob * d,
+            oa * b + ob * e,
+            oa * xoff + ob * yoff + oxoff,
+            od * a + oe * d,
+            od * b + oe * e,
+            od * xoff + oe * yoff + oyoff,
+        )
+    }
+
+    /// Apply this transformation to a single coordinate.
+    pub fn apply(&self, [x, y]: [f64; 2]) -> [f64; 2] {
+        let [a, b, xoff, d, e, yoff] = self.0;
+        [a * x + b * y + xoff, d * x + e * y + yoff]
+    }
+}
@@ -0,0 +1,117 @@
+use std::f64::consts::{PI, TAU};
+
+use crate::error::GeometryError;
+use crate::overlay;
+use crate::polygon::{MultiPolygon, Polygon};
+
+/// The coordinate `radius` away from `center` in the direction of `angle`.
+///
+/// Every piece of a buffer computes its coordinates through this function, so that
+/// pieces meeting at the same angle around the same vertex share exact coordinates.
+fn around(center: [f64; 2], radius: f64, angle: f64) -> [f64; 2] {
+    [
+        center[0] + radius * angle.cos(),
+        center[1] + radius * angle.sin(),
+    ]
+}
+
+/// The angle of the left normal of the edge from `start` to `end`.
+fn normal_angle(start: [f64; 2], end: [f64; 2]) -> f64 {
+    (end[1] - start[1]).atan2(end[0] - start[0]) + PI / 2.
+}
+
+impl Polygon {
+    /// Grow this `Polygon` by `distance`, or shrink it if `distance` is negative.
+    ///
+    /// The result is built from a stroke around every ring: a rectangle `distance` wide
+    /// on either side of each edge and a disc of radius `distance` around each vertex.
+    /// The stroke is added to the `Polygon` to grow it and removed from it to shrink it.
+    /// Each disc is approximated with `segments` straight segments per quarter circle,
+    /// so convex corners are rounded when growing and concave corners when shrinking.
+    ///
+    /// Shrinking can split the `Polygon` into several parts or eliminate it entirely,
+    /// hence the `MultiPolygon` result.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use std::f64::consts::PI;
+    ///
+    /// use auto_gis_with_rust::polygon::Polygon;
+    ///
+    /// let square = Polygon::new(vec![vec![[0, 0], [4, 0], [4, 4], [0, 4]]]).unwrap();
+    ///
+    /// // Each side moves out by 1, and the four rounded corners add up to a circle.
+    /// let grown = square.buffer(1., 16).unwrap();
+    /// assert!((grown.area() - (16. + 4. * 4. + PI)).abs() < 0.01);
+    ///
+    /// let shrunk = square.buffer(-1., 16).unwrap();
+    /// assert!((shrunk.area() - 4.).abs() < 1e-9);
+    ///
+    /// assert!(square.buffer(-3., 16).unwrap().is_empty());
+    /// ```
+    pub fn buffer(&self, distance: f64, segments: usize) -> Result<MultiPolygon, GeometryError> {
+        if distance == 0. {
+            return Ok(MultiPolygon::new(vec![self.clone()]));
+        }
+        let radius = distance.abs();
+        let steps = 4 * segments.max(1);
+
+        let mut stroke = Vec::new();
+        for ring in self.iter() {
+            let edges: Vec<[[f64; 2]; 2]> = ring
+                .windows(2)
+                .filter(|pair| pair[0] != pair[1])
+                .map(|pair| [pair[0], pair[1]])
+                .collect();
+            for (index, [start, end]) in edges.iter().copied().enumerate() {
+                let angle = normal_angle(start, end);
+                stroke.push(Polygon::new(vec![vec![
+                    around(start, radius, angle),
+                    around(end, radius, angle),
+                    around(end, radius, angle + PI),
+                    around(start, radius, angle + PI),
+                ]])?);
+
+                // The disc around the start of this edge also passes through the corners
+                // of the rectangles on both edges that meet there.
+                let [previous_start, previous_end] = edges[(index + edges.len() - 1) % edges.len()];
+                let previous_angle = normal_angle(previous_start, previous_end);
+                let corners = [angle, angle + PI, previous_angle, previous_angle + PI];
+                let mut angles: Vec<f64> = (0..steps)
+                    .map(|step| step as f64 * TAU / steps as f64)
+                    .filter(|uniform| {
+                        corners.iter().all(|corner| {
+                            let difference = (uniform - corner).rem_euclid(TAU);
+                            difference.min(TAU - difference) > 1e-9
+                        })
+                    })
+                    .collect();
+                angles.extend(corners);
+                angles.sort_by(|a, b| a.rem_euclid(TAU).partial_cmp(&b.rem_euclid(TAU)).unwrap());
+                angles.dedup_by(|a, b| around(start, radius, *a) == around(start, radius, *b));
+                let disc: Vec<[f64; 2]> = angles
+                    .into_iter()
+                    .map(|angle| around(start, radius, angle))
+                    .collect();
+                stroke.push(Polygon::new(vec![disc])?);
+            }
+        }
+
+        let stroke: Vec<&Polygon> = stroke.iter().collect();
+        let buffered = if distance > 0. {
+            overlay::overlay(&[&[self], &stroke], |inside| inside[0] || inside[1])?
+        } else {
+            overlay::overlay(&[&[self], &stroke], |inside| inside[0] && !inside[1])?
+        };
+        // Rounding where pieces of the stroke nearly coincide can leave slivers with no
+        // real area behind.
+        Ok(MultiPolygon::new(
+            buffered
+                .into_polygons()
+                .into_iter()
+                .filter(|polygon| polygon.area() > 1e-9 * radius * radius)
+                .collect(),
+        ))
+    }
+}
@@ -4,4 +4,20 @@ use thiserror::Error;
 pub enum GeometryError {
     #[error("too few coordinates, expected 2 or more, found {0})")]
     TooFewCoords(usize),
+    #[error("invalid coordinate dimension, expected 2, 3 or 4 values, found {0}")]
+    InvalidDimension(usize),
+    #[error("flat coordinates must come in x, y pairs, found {0} values")]
+    OddCoordinateCount(usize),
+    #[error("coordinate at index {0} cannot be represented as f64")]
+    InvalidCoordinate(usize),
+    #[error("invalid encoded polyline: {0}")]
+    InvalidPolyline(String),
+    #[error("invalid geohash: {0}")]
+    InvalidGeohash(String),
+    #[error("invalid WKT: {0}")]
+    InvalidWkt(String),
+    #[error("invalid WKB: {0}")]
+    InvalidWkb(String),
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
 }
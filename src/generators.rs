@@ -0,0 +1,217 @@
+use std::f64::consts::PI;
+
+use crate::error::GeometryError;
+use crate::line_string::LineString;
+use crate::point::{MultiPoint, Point};
+use crate::polygon::Polygon;
+
+/// Generate a regular grid of `nx` by `ny` `Point`s, starting at `origin` and spaced
+/// `dx` apart along the x-axis and `dy` apart along the y-axis.
+///
+/// The `Point`s are ordered row by row, i.e. x varies fastest.
+///
+/// # Examples:
+///
+/// ```
+/// use auto_gis_with_rust::generators::point_grid;
+/// use auto_gis_with_rust::point::Point;
+///
+/// let grid = point_grid(Point::new(0, 0), 2, 2, 1., 2.);
+///
+/// assert_eq!("MULTIPOINT ((0 0), (1 0), (0 2), (1 2))", grid.to_string());
+/// ```
+pub fn point_grid(origin: Point, nx: usize, ny: usize, dx: f64, dy: f64) -> MultiPoint {
+    let points: Vec<Point> = (0..ny)
+        .flat_map(|row| {
+            (0..nx).map(move |column| {
+                Point::new(
+                    origin.x() + column as f64 * dx,
+                    origin.y() + row as f64 * dy,
+                )
+            })
+        })
+        .collect();
+    MultiPoint::new(points)
+}
+
+/// Generate a regular `Polygon` with `sides` vertices lying on a circle of `radius`
+/// around `center`.
+///
+/// The exterior ring runs counter-clockwise and is rotated so that its bottom edge is
+/// horizontal.
+///
+/// # Examples:
+///
+/// A 4-gon inscribed in a circle of radius √2 is a square with sides of length 2.
+///
+/// ```
+/// use auto_gis_with_rust::generators::regular_polygon;
+/// use auto_gis_with_rust::point::Point;
+///
+/// let square = regular_polygon(Point::new(0, 0), 2f64.sqrt(), 4).unwrap();
+/// let expected = [[1., -1.], [1., 1.], [-1., 1.], [-1., -1.], [1., -1.]];
+///
+/// assert_eq!(square[0].len(), 5);
+/// for (vertex, expected_vertex) in square[0].iter().zip(expected) {
+///     assert!((vertex[0] - expected_vertex[0]).abs() < 1e-12);
+///     assert!((vertex[1] - expected_vertex[1]).abs() < 1e-12);
+/// }
+/// ```
+///
+/// A polygon needs at least three sides.
+///
+/// ```
+/// # use auto_gis_with_rust::generators::regular_polygon;
+/// # use auto_gis_with_rust::point::Point;
+/// assert!(regular_polygon(Point::new(0, 0), 1., 2).is_err());
+/// ```
+pub fn regular_polygon(center: Point, radius: f64, sides: usize) -> Result<Polygon, GeometryError> {
+    if sides < 3 {
+        return Err(GeometryError::TooFewCoords(sides));
+    }
+    let step = 2. * PI / sides as f64;
+    let start = step / 2. - PI / 2.;
+    let ring: Vec<[f64; 2]> = (0..sides)
+        .map(|side| {
+            let angle = start + side as f64 * step;
+            [
+                center.x() + radius * angle.cos(),
+                center.y() + radius * angle.sin(),
+            ]
+        })
+        .collect();
+    Polygon::new(vec![ring])
+}
+
+/// Approximate a circular arc of `radius` around `center` as a `LineString` made of
+/// `segments` straight segments.
+///
+/// Angles are in radians, measured counter-clockwise from the positive x-axis. The arc
+/// always runs in the direction of increasing angle, so if `end_angle` is smaller than
+/// `start_angle` it wraps around through a full turn. The first and last vertices lie
+/// exactly at `start_angle` and `end_angle`. At least one segment is always produced.
+///
+/// # Examples:
+///
+/// ```
+/// use std::f64::consts::FRAC_PI_2;
+/// use auto_gis_with_rust::generators::arc;
+/// use auto_gis_with_rust::point::Point;
+///
+/// let quarter_circle = arc(Point::new(0, 0), 1., 0., FRAC_PI_2, 8);
+///
+/// assert_eq!(quarter_circle.len(), 9);
+/// assert_eq!(quarter_circle[0], [1., 0.]);
+///
+/// let [x, y] = quarter_circle[8];
+/// assert!(x.abs() < 1e-12);
+/// assert_eq!(y, 1.);
+/// ```
+pub fn arc(
+    center: Point,
+    radius: f64,
+    start_angle: f64,
+    end_angle: f64,
+    segments: usize,
+) -> LineString {
+    let segments = segments.max(1);
+    let mut sweep = end_angle - start_angle;
+    if sweep < 0. {
+        sweep += 2. * PI;
+    }
+    let coordinates: Vec<[f64; 2]> = (0..=segments)
+        .map(|segment| {
+            let angle = if segment == segments {
+                end_angle
+            } else {
+                start_angle + sweep * segment as f64 / segments as f64
+            };
+            [
+                center.x() + radius * angle.cos(),
+                center.y() + radius * angle.sin(),
+            ]
+        })
+        .collect();
+    LineString::new(coordinates).unwrap()
+}
+
+/// Flatten a quadratic Bézier curve with start `p0`, control `p1` and end `p2` into a
+/// `LineString` of `segments` straight segments, sampled at even parameter steps.
+///
+/// The first and last vertices are exactly `p0` and `p2`.
+///
+/// # Examples:
+///
+/// ```
+/// use auto_gis_with_rust::generators::quadratic_bezier;
+/// use auto_gis_with_rust::point::Point;
+///
+/// let curve = quadratic_bezier(Point::new(0, 0), Point::new(1, 2), Point::new(2, 0), 2);
+///
+/// assert_eq!(curve.to_string(), "LINESTRING (0 0, 1 1, 2 0)");
+/// ```
+pub fn quadratic_bezier(p0: Point, p1: Point, p2: Point, segments: usize) -> LineString {
+    bezier(&[p0, p1, p2], segments)
+}
+
+/// Flatten a cubic Bézier curve with start `p0`, controls `p1` and `p2` and end `p3`
+/// into a `LineString` of `segments` straight segments, sampled at even parameter steps.
+///
+/// The first and last vertices are exactly `p0` and `p3`, and more segments follow the
+/// true curve more closely.
+///
+/// # Examples:
+///
+/// Control points lying on a straight line produce a straight `LineString`.
+///
+/// ```
+/// use auto_gis_with_rust::generators::cubic_bezier;
+/// use auto_gis_with_rust::point::Point;
+///
+/// let curve = cubic_bezier(
+///     Point::new(0, 0),
+///     Point::new(1, 1),
+///     Point::new(2, 2),
+///     Point::new(3, 3),
+///     6,
+/// );
+///
+/// assert_eq!(curve.len(), 7);
+/// assert_eq!(curve[0], [0., 0.]);
+/// assert_eq!(curve[6], [3., 3.]);
+/// assert!(curve.iter().all(|[x, y]| (x - y).abs() < 1e-12));
+/// ```
+pub fn cubic_bezier(p0: Point, p1: Point, p2: Point, p3: Point, segments: usize) -> LineString {
+    bezier(&[p0, p1, p2, p3], segments)
+}
+
+/// Sample the Bézier curve defined by `control_points` using de Casteljau's algorithm.
+fn bezier(control_points: &[Point], segments: usize) -> LineString {
+    let segments = segments.max(1);
+    let coordinates: Vec<[f64; 2]> = (0..=segments)
+        .map(|segment| {
+            if segment == segments {
+                let end = control_points[control_points.len() - 1];
+                return [end.x(), end.y()];
+            }
+            let t = segment as f64 / segments as f64;
+            let mut points: Vec<[f64; 2]> = control_points
+                .iter()
+                .map(|point| [point.x(), point.y()])
+                .collect();
+            while points.len() > 1 {
+                points = points
+                    .windows(2)
+                    .map(|pair| {
+                        [
+                            pair[0][0] + t * (pair[1][0] - pair[0][0]),
+                            pair[0][1] + t * (pair[1][1] - pair[0][1]),
+                        ]
+                    })
+                    .collect();
+            }
+            points[0]
+        })
+        .collect();
+    LineString::new(coordinates).unwrap()
+}
@@ -0,0 +1,183 @@
+//! Conversions between this crate's geometries and those of the [`geo_types`] crate,
+//! enabled by the `geo-interop` feature.
+//!
+//! Conversions into `geo_types` always succeed. Conversions from `geo_types` fail with
+//! `GeometryError::TooFewCoords` where `geo_types` allows fewer coordinates than this
+//! crate does, e.g. for an empty `LineString`.
+
+use std::convert::TryFrom;
+
+use crate::error::GeometryError;
+use crate::geometry::Geometry;
+use crate::line_string::{LineString, MultiLineString};
+use crate::point::{MultiPoint, Point};
+use crate::polygon::{MultiPolygon, Polygon, PolygonRing};
+
+fn coordinates(line_string: &geo_types::LineString<f64>) -> Vec<[f64; 2]> {
+    line_string
+        .coords()
+        .map(|coord| [coord.x, coord.y])
+        .collect()
+}
+
+impl From<Point> for geo_types::Point<f64> {
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// let point: geo_types::Point<f64> = Point::new(0., 1.).into();
+    ///
+    /// assert_eq!(point, geo_types::Point::new(0., 1.));
+    /// ```
+    fn from(point: Point) -> Self {
+        geo_types::Point::new(point.x(), point.y())
+    }
+}
+
+impl From<geo_types::Point<f64>> for Point {
+    fn from(point: geo_types::Point<f64>) -> Self {
+        Point::new(point.x(), point.y())
+    }
+}
+
+impl From<MultiPoint> for geo_types::MultiPoint<f64> {
+    fn from(multi_point: MultiPoint) -> Self {
+        geo_types::MultiPoint(multi_point.into_iter().map(Into::into).collect())
+    }
+}
+
+impl From<geo_types::MultiPoint<f64>> for MultiPoint {
+    fn from(multi_point: geo_types::MultiPoint<f64>) -> Self {
+        MultiPoint::new(multi_point.into_iter().map(Into::into).collect())
+    }
+}
+
+impl From<LineString> for geo_types::LineString<f64> {
+    fn from(line_string: LineString) -> Self {
+        geo_types::LineString::from(line_string.to_vec())
+    }
+}
+
+impl TryFrom<geo_types::LineString<f64>> for LineString {
+    type Error = GeometryError;
+
+    /// Tries to convert a `geo_types::LineString`, which may have fewer than two
+    /// coordinates, into a `LineString`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let line_string = LineString::new(vec![[0., 0.], [1., 0.], [1., 1.]]).unwrap();
+    /// let geo_line_string = geo_types::LineString::from(line_string.clone());
+    ///
+    /// assert_eq!(LineString::try_from(geo_line_string).unwrap(), line_string);
+    /// assert!(LineString::try_from(geo_types::LineString::<f64>::new(vec![])).is_err());
+    /// ```
+    fn try_from(line_string: geo_types::LineString<f64>) -> Result<Self, GeometryError> {
+        LineString::new(coordinates(&line_string))
+    }
+}
+
+impl From<MultiLineString> for geo_types::MultiLineString<f64> {
+    fn from(multi_line_string: MultiLineString) -> Self {
+        geo_types::MultiLineString(multi_line_string.into_iter().map(Into::into).collect())
+    }
+}
+
+impl TryFrom<geo_types::MultiLineString<f64>> for MultiLineString {
+    type Error = GeometryError;
+
+    fn try_from(multi_line_string: geo_types::MultiLineString<f64>) -> Result<Self, GeometryError> {
+        let line_strings: Result<Vec<LineString>, GeometryError> = multi_line_string
+            .into_iter()
+            .map(LineString::try_from)
+            .collect();
+        Ok(MultiLineString::new(line_strings?))
+    }
+}
+
+impl From<Polygon> for geo_types::Polygon<f64> {
+    /// The first ring becomes the exterior and the others the interiors. Rings stay closed.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use auto_gis_with_rust::polygon::Polygon;
+    ///
+    /// let polygon = Polygon::new(vec![
+    ///     vec![[0, 0], [0, 3], [3, 3], [3, 0]],
+    ///     vec![[1, 1], [2, 1], [2, 2], [1, 2]],
+    /// ]).unwrap();
+    /// let geo_polygon = geo_types::Polygon::from(polygon.clone());
+    ///
+    /// assert_eq!(geo_polygon.exterior().0.len(), 5);
+    /// assert_eq!(geo_polygon.interiors().len(), 1);
+    /// assert_eq!(Polygon::try_from(geo_polygon).unwrap(), polygon);
+    /// ```
+    fn from(polygon: Polygon) -> Self {
+        let mut rings = polygon
+            .iter()
+            .map(|ring| geo_types::LineString::from(ring.to_vec()));
+        let exterior = rings
+            .next()
+            .unwrap_or_else(|| geo_types::LineString::new(vec![]));
+        geo_types::Polygon::new(exterior, rings.collect())
+    }
+}
+
+impl TryFrom<geo_types::Polygon<f64>> for Polygon {
+    type Error = GeometryError;
+
+    fn try_from(polygon: geo_types::Polygon<f64>) -> Result<Self, GeometryError> {
+        let rings: Vec<Vec<[f64; 2]>> = std::iter::once(polygon.exterior())
+            .chain(polygon.interiors())
+            .map(coordinates)
+            .collect();
+        for ring in &rings {
+            PolygonRing::new(ring.clone())?;
+        }
+        Polygon::new(rings)
+    }
+}
+
+impl From<MultiPolygon> for geo_types::MultiPolygon<f64> {
+    fn from(multi_polygon: MultiPolygon) -> Self {
+        geo_types::MultiPolygon(multi_polygon.into_iter().map(Into::into).collect())
+    }
+}
+
+impl TryFrom<geo_types::MultiPolygon<f64>> for MultiPolygon {
+    type Error = GeometryError;
+
+    fn try_from(multi_polygon: geo_types::MultiPolygon<f64>) -> Result<Self, GeometryError> {
+        let polygons: Result<Vec<Polygon>, GeometryError> =
+            multi_polygon.into_iter().map(Polygon::try_from).collect();
+        Ok(MultiPolygon::new(polygons?))
+    }
+}
+
+impl From<Geometry> for geo_types::Geometry<f64> {
+    fn from(geometry: Geometry) -> Self {
+        match geometry {
+            Geometry::Point(point) => geo_types::Geometry::Point(point.into()),
+            Geometry::MultiPoint(multi_point) => {
+                geo_types::Geometry::MultiPoint(multi_point.into())
+            }
+            Geometry::LineString(line_string) => {
+                geo_types::Geometry::LineString(line_string.into())
+            }
+            Geometry::MultiLineString(multi_line_string) => {
+                geo_types::Geometry::MultiLineString(multi_line_string.into())
+            }
+            Geometry::Polygon(polygon) => geo_types::Geometry::Polygon(polygon.into()),
+            Geometry::MultiPolygon(multi_polygon) => {
+                geo_types::Geometry::MultiPolygon(multi_polygon.into())
+            }
+        }
+    }
+}
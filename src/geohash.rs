@@ -0,0 +1,88 @@
+use crate::error::GeometryError;
+use crate::point::Point;
+
+const BASE_32: &[u8; 32] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+
+/// Encode a `Point` as a geohash of `length` characters, treating its x-coordinate as
+/// longitude and its y-coordinate as latitude.
+///
+/// Longer geohashes identify smaller cells.
+///
+/// # Examples:
+///
+/// ```
+/// use auto_gis_with_rust::geohash::geohash_encode;
+/// use auto_gis_with_rust::point::Point;
+///
+/// let san_francisco = Point::new(-122.4194, 37.7749);
+///
+/// assert_eq!(geohash_encode(&san_francisco, 5), "9q8yy");
+/// assert_eq!(geohash_encode(&Point::new(10.40744, 57.64911), 11), "u4pruydqqvj");
+/// ```
+pub fn geohash_encode(p: &Point, length: usize) -> String {
+    let mut ranges = [[-180., 180.], [-90., 90.]];
+    let values = [p.x(), p.y()];
+    let mut hash = String::with_capacity(length);
+    let mut bit = 0;
+    for _ in 0..length {
+        let mut index = 0;
+        for _ in 0..5 {
+            let axis = bit % 2;
+            let middle = (ranges[axis][0] + ranges[axis][1]) / 2.;
+            index <<= 1;
+            if values[axis] >= middle {
+                index |= 1;
+                ranges[axis][0] = middle;
+            } else {
+                ranges[axis][1] = middle;
+            }
+            bit += 1;
+        }
+        hash.push(BASE_32[index] as char);
+    }
+    hash
+}
+
+/// Decode a geohash into the `Point` at the center of its cell, with longitude as the
+/// x-coordinate and latitude as the y-coordinate.
+///
+/// Returns `GeometryError::InvalidGeohash` if the geohash is empty or contains a
+/// character outside the geohash alphabet.
+///
+/// # Examples:
+///
+/// ```
+/// use auto_gis_with_rust::geohash::geohash_decode;
+///
+/// let center = geohash_decode("9q8yy").unwrap();
+///
+/// assert!((center.x() - -122.4194).abs() < 0.03);
+/// assert!((center.y() - 37.7749).abs() < 0.03);
+///
+/// assert!(geohash_decode("9q8ya").is_err());
+/// ```
+pub fn geohash_decode(hash: &str) -> Result<Point, GeometryError> {
+    if hash.is_empty() {
+        return Err(GeometryError::InvalidGeohash(hash.to_string()));
+    }
+    let mut ranges = [[-180., 180.], [-90., 90.]];
+    let mut bit = 0;
+    for character in hash.bytes() {
+        let index = BASE_32
+            .iter()
+            .position(|candidate| *candidate == character.to_ascii_lowercase())
+            .ok_or_else(|| GeometryError::InvalidGeohash(hash.to_string()))?;
+        for shift in (0..5).rev() {
+            let axis = bit % 2;
+            let middle = (ranges[axis][0] + ranges[axis][1]) / 2.;
+            if index >> shift & 1 == 1 {
+                ranges[axis][0] = middle;
+            } else {
+                ranges[axis][1] = middle;
+            }
+            bit += 1;
+        }
+    }
+    let [[west, east], [south, north]] = ranges;
+    Ok(Point::new((west + east) / 2., (south + north) / 2.))
+}
@@ -0,0 +1,305 @@
+use std::fmt;
+
+use crate::line_string::{LineString, MultiLineString};
+use crate::point::{MultiPoint, Point};
+use crate::polygon::{MultiPolygon, Polygon};
+use crate::rect::Rect;
+use crate::traits::{self, Dimensions, Envelope, GeometryType, MapCoordinates};
+
+/// Any one of the concrete geometry types.
+///
+/// # Examples:
+///
+/// ```
+/// use auto_gis_with_rust::geometry::Geometry;
+/// use auto_gis_with_rust::point::Point;
+///
+/// let geometry = Geometry::from(Point::new(0, 1));
+///
+/// assert_eq!(geometry.to_string(), "POINT (0 1)");
+/// ```
+#[derive(Debug, PartialEq, PartialOrd, Clone)]
+pub enum Geometry {
+    Point(Point),
+    MultiPoint(MultiPoint),
+    LineString(LineString),
+    MultiLineString(MultiLineString),
+    Polygon(Polygon),
+    MultiPolygon(MultiPolygon),
+}
+
+impl Geometry {
+    /// The boundary of this geometry, as defined by the OGC Simple Features specification.
+    ///
+    /// See the `boundary` method of each geometry type for details.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::geometry::Geometry;
+    /// use auto_gis_with_rust::polygon::Polygon;
+    ///
+    /// let polygon = Polygon::new(vec![vec![[0, 0], [0, 1], [1, 1]]]).unwrap();
+    /// let boundary = Geometry::Polygon(polygon).boundary();
+    ///
+    /// assert_eq!(boundary.to_string(), "MULTILINESTRING ((0 0, 0 1, 1 1, 0 0))");
+    /// ```
+    pub fn boundary(&self) -> Geometry {
+        match self {
+            Geometry::Point(point) => point.boundary(),
+            Geometry::MultiPoint(multi_point) => multi_point.boundary(),
+            Geometry::LineString(line_string) => line_string.boundary(),
+            Geometry::MultiLineString(multi_line_string) => multi_line_string.boundary(),
+            Geometry::Polygon(polygon) => polygon.boundary(),
+            Geometry::MultiPolygon(multi_polygon) => multi_polygon.boundary(),
+        }
+    }
+
+    /// The centroid of the inner geometry, or `None` if it is an empty `MultiLineString` or
+    /// `MultiPolygon`, which have no centroid.
+    ///
+    /// Unlike [`centroid`](traits::Geometry::centroid), this never panics.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::geometry::Geometry;
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// let square: Geometry = "MULTIPOLYGON (((0 0, 2 0, 2 2, 0 2, 0 0)))".parse().unwrap();
+    /// let empty: Geometry = "MULTIPOLYGON EMPTY".parse().unwrap();
+    /// let no_lines: Geometry = "MULTILINESTRING EMPTY".parse().unwrap();
+    ///
+    /// assert_eq!(square.checked_centroid(), Some(Point::new(1, 1)));
+    /// assert_eq!(empty.checked_centroid(), None);
+    /// assert_eq!(no_lines.checked_centroid(), None);
+    /// ```
+    pub fn checked_centroid(&self) -> Option<Point> {
+        match self {
+            Geometry::MultiLineString(multi_line_string) => multi_line_string.checked_centroid(),
+            Geometry::MultiPolygon(multi_polygon) => multi_polygon.checked_centroid(),
+            geometry => Some(traits::Geometry::centroid(geometry)),
+        }
+    }
+}
+
+impl fmt::Display for Geometry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Geometry::Point(point) => point.fmt(f),
+            Geometry::MultiPoint(multi_point) => multi_point.fmt(f),
+            Geometry::LineString(line_string) => line_string.fmt(f),
+            Geometry::MultiLineString(multi_line_string) => multi_line_string.fmt(f),
+            Geometry::Polygon(polygon) => polygon.fmt(f),
+            Geometry::MultiPolygon(multi_polygon) => multi_polygon.fmt(f),
+        }
+    }
+}
+
+impl Dimensions for Geometry {
+    /// The topological dimension of the inner geometry.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::geometry::Geometry;
+    /// use auto_gis_with_rust::line_string::LineString;
+    /// use auto_gis_with_rust::point::Point;
+    /// use auto_gis_with_rust::traits::Dimensions;
+    ///
+    /// let point = Geometry::from(Point::new(0, 1));
+    /// let line_string = Geometry::from(LineString::new(vec![[0, 0], [1, 1]]).unwrap());
+    ///
+    /// assert_eq!(point.dimension(), 0);
+    /// assert_eq!(line_string.dimension(), 1);
+    /// ```
+    fn dimension(&self) -> u8 {
+        match self {
+            Geometry::Point(point) => point.dimension(),
+            Geometry::MultiPoint(multi_point) => multi_point.dimension(),
+            Geometry::LineString(line_string) => line_string.dimension(),
+            Geometry::MultiLineString(multi_line_string) => multi_line_string.dimension(),
+            Geometry::Polygon(polygon) => polygon.dimension(),
+            Geometry::MultiPolygon(multi_polygon) => multi_polygon.dimension(),
+        }
+    }
+
+    fn coordinate_dimension(&self) -> u8 {
+        match self {
+            Geometry::Point(point) => point.coordinate_dimension(),
+            Geometry::MultiPoint(multi_point) => multi_point.coordinate_dimension(),
+            Geometry::LineString(line_string) => line_string.coordinate_dimension(),
+            Geometry::MultiLineString(multi_line_string) => {
+                multi_line_string.coordinate_dimension()
+            }
+            Geometry::Polygon(polygon) => polygon.coordinate_dimension(),
+            Geometry::MultiPolygon(multi_polygon) => multi_polygon.coordinate_dimension(),
+        }
+    }
+}
+
+impl Envelope for Geometry {
+    /// The envelope of the inner geometry.
+    fn envelope(&self) -> Option<Rect> {
+        match self {
+            Geometry::Point(point) => point.envelope(),
+            Geometry::MultiPoint(multi_point) => multi_point.envelope(),
+            Geometry::LineString(line_string) => line_string.envelope(),
+            Geometry::MultiLineString(multi_line_string) => multi_line_string.envelope(),
+            Geometry::Polygon(polygon) => polygon.envelope(),
+            Geometry::MultiPolygon(multi_polygon) => multi_polygon.envelope(),
+        }
+    }
+}
+
+impl traits::Geometry for Geometry {
+    /// The centroid of the inner geometry.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::geometry::Geometry;
+    /// use auto_gis_with_rust::polygon::Polygon;
+    /// use auto_gis_with_rust::traits::Geometry as _;
+    ///
+    /// let polygon = Polygon::new(vec![vec![[0, 0], [4, 0], [4, 2], [0, 2]]]).unwrap();
+    ///
+    /// assert_eq!(Geometry::Polygon(polygon.clone()).centroid(), polygon.centroid());
+    /// ```
+    fn centroid(&self) -> Point {
+        match self {
+            Geometry::Point(point) => point.centroid(),
+            Geometry::MultiPoint(multi_point) => multi_point.centroid(),
+            Geometry::LineString(line_string) => line_string.centroid(),
+            Geometry::MultiLineString(multi_line_string) => multi_line_string.centroid(),
+            Geometry::Polygon(polygon) => polygon.centroid(),
+            Geometry::MultiPolygon(multi_polygon) => multi_polygon.centroid(),
+        }
+    }
+
+    /// Returns true if the inner geometry is simple.
+    fn is_simple(&self) -> bool {
+        match self {
+            Geometry::Point(point) => point.is_simple(),
+            Geometry::MultiPoint(multi_point) => multi_point.is_simple(),
+            Geometry::LineString(line_string) => line_string.is_simple(),
+            Geometry::MultiLineString(multi_line_string) => multi_line_string.is_simple(),
+            Geometry::Polygon(polygon) => polygon.is_simple(),
+            Geometry::MultiPolygon(multi_polygon) => multi_polygon.is_simple(),
+        }
+    }
+}
+
+impl MapCoordinates for Geometry {
+    /// Apply `function` to every coordinate of the inner geometry, keeping its variant.
+    ///
+    /// The provided methods, such as [`transform`](MapCoordinates::transform), therefore
+    /// work on a `Geometry` of any type.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::affine::Affine;
+    /// use auto_gis_with_rust::geometry::Geometry;
+    /// use auto_gis_with_rust::line_string::LineString;
+    /// use auto_gis_with_rust::point::Point;
+    /// use auto_gis_with_rust::traits::MapCoordinates;
+    ///
+    /// let geometries = vec![
+    ///     Geometry::from(Point::new(0, 1)),
+    ///     Geometry::from(LineString::new(vec![[0, 0], [1, 2]]).unwrap()),
+    /// ];
+    /// let moved: Vec<Geometry> = geometries
+    ///     .iter()
+    ///     .map(|geometry| geometry.transform(&Affine::translation(1., 1.)))
+    ///     .collect();
+    ///
+    /// assert_eq!(moved[0].to_string(), "POINT (1 2)");
+    /// assert_eq!(moved[1].to_string(), "LINESTRING (1 1, 2 3)");
+    /// ```
+    fn map_coordinates<F: Fn([f64; 2]) -> [f64; 2]>(&self, function: F) -> Self {
+        match self {
+            Geometry::Point(point) => Geometry::Point(point.map_coordinates(function)),
+            Geometry::MultiPoint(multi_point) => {
+                Geometry::MultiPoint(multi_point.map_coordinates(function))
+            }
+            Geometry::LineString(line_string) => {
+                Geometry::LineString(line_string.map_coordinates(function))
+            }
+            Geometry::MultiLineString(multi_line_string) => {
+                Geometry::MultiLineString(multi_line_string.map_coordinates(function))
+            }
+            Geometry::Polygon(polygon) => Geometry::Polygon(polygon.map_coordinates(function)),
+            Geometry::MultiPolygon(multi_polygon) => {
+                Geometry::MultiPolygon(multi_polygon.map_coordinates(function))
+            }
+        }
+    }
+
+    #[cfg(feature = "parallel")]
+    fn par_map_coordinates<F: Fn([f64; 2]) -> [f64; 2] + Sync + Send>(&self, function: F) -> Self {
+        match self {
+            Geometry::Point(point) => Geometry::Point(point.par_map_coordinates(function)),
+            Geometry::MultiPoint(multi_point) => {
+                Geometry::MultiPoint(multi_point.par_map_coordinates(function))
+            }
+            Geometry::LineString(line_string) => {
+                Geometry::LineString(line_string.par_map_coordinates(function))
+            }
+            Geometry::MultiLineString(multi_line_string) => {
+                Geometry::MultiLineString(multi_line_string.par_map_coordinates(function))
+            }
+            Geometry::Polygon(polygon) => Geometry::Polygon(polygon.par_map_coordinates(function)),
+            Geometry::MultiPolygon(multi_polygon) => {
+                Geometry::MultiPolygon(multi_polygon.par_map_coordinates(function))
+            }
+        }
+    }
+}
+
+impl GeometryType for Geometry {
+    /// The type name of the inner geometry.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::geometry::Geometry;
+    /// use auto_gis_with_rust::polygon::Polygon;
+    /// use auto_gis_with_rust::traits::GeometryType;
+    ///
+    /// let polygon = Polygon::new(vec![vec![[0, 0], [0, 1], [1, 1]]]).unwrap();
+    ///
+    /// assert_eq!(Geometry::from(polygon).geometry_type(), "Polygon");
+    /// ```
+    fn geometry_type(&self) -> &'static str {
+        match self {
+            Geometry::Point(point) => point.geometry_type(),
+            Geometry::MultiPoint(multi_point) => multi_point.geometry_type(),
+            Geometry::LineString(line_string) => line_string.geometry_type(),
+            Geometry::MultiLineString(multi_line_string) => multi_line_string.geometry_type(),
+            Geometry::Polygon(polygon) => polygon.geometry_type(),
+            Geometry::MultiPolygon(multi_polygon) => multi_polygon.geometry_type(),
+        }
+    }
+}
+
+macro_rules! implement_from {
+    ($($variant:ident),*) => {
+        $(
+            impl From<$variant> for Geometry {
+                fn from(geometry: $variant) -> Self {
+                    Geometry::$variant(geometry)
+                }
+            }
+        )*
+    };
+}
+
+implement_from!(
+    Point,
+    MultiPoint,
+    LineString,
+    MultiLineString,
+    Polygon,
+    MultiPolygon
+);
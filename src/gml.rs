@@ -0,0 +1,178 @@
+use itertools::Itertools;
+
+use crate::geometry::Geometry;
+use crate::line_string::{LineString, MultiLineString};
+use crate::point::{MultiPoint, Point};
+use crate::polygon::{MultiPolygon, Polygon};
+
+/// The order in which coordinate values are written.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum AxisOrder {
+    /// x before y, i.e. longitude before latitude.
+    XY,
+    /// y before x, i.e. latitude before longitude, as EPSG:4326 prescribes.
+    YX,
+}
+
+/// Serializes a geometry as GML 3.2.
+pub trait ToGml {
+    /// This geometry as GML 3.2, writing coordinates in `order`.
+    fn to_gml_with(&self, order: AxisOrder) -> String;
+
+    /// This geometry as GML 3.2, writing x before y.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::gml::{AxisOrder, ToGml};
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// let point = Point::new(1, 2);
+    ///
+    /// assert_eq!(point.to_gml(), "<gml:Point><gml:pos>1 2</gml:pos></gml:Point>");
+    /// assert_eq!(point.to_gml_with(AxisOrder::YX), "<gml:Point><gml:pos>2 1</gml:pos></gml:Point>");
+    /// ```
+    fn to_gml(&self) -> String {
+        self.to_gml_with(AxisOrder::XY)
+    }
+}
+
+fn positions(coordinates: &[[f64; 2]], order: AxisOrder) -> String {
+    coordinates
+        .iter()
+        .map(|[x, y]| match order {
+            AxisOrder::XY => format!("{} {}", x, y),
+            AxisOrder::YX => format!("{} {}", y, x),
+        })
+        .join(" ")
+}
+
+impl ToGml for Point {
+    fn to_gml_with(&self, order: AxisOrder) -> String {
+        format!(
+            "<gml:Point><gml:pos>{}</gml:pos></gml:Point>",
+            positions(&[[self.x(), self.y()]], order)
+        )
+    }
+}
+
+impl ToGml for LineString {
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::gml::ToGml;
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let line_string = LineString::new(vec![[0, 0], [1, 2]]).unwrap();
+    ///
+    /// assert_eq!(
+    ///     line_string.to_gml(),
+    ///     "<gml:LineString><gml:posList>0 0 1 2</gml:posList></gml:LineString>",
+    /// );
+    /// ```
+    fn to_gml_with(&self, order: AxisOrder) -> String {
+        format!(
+            "<gml:LineString><gml:posList>{}</gml:posList></gml:LineString>",
+            positions(self, order)
+        )
+    }
+}
+
+impl ToGml for Polygon {
+    /// The first ring is written as the `exterior` and any others as `interior`s.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::gml::ToGml;
+    /// use auto_gis_with_rust::polygon::Polygon;
+    ///
+    /// let polygon = Polygon::new(vec![
+    ///     vec![[0, 0], [3, 0], [3, 3], [0, 3]],
+    ///     vec![[1, 1], [1, 2], [2, 2], [2, 1]],
+    /// ]).unwrap();
+    ///
+    /// assert_eq!(
+    ///     polygon.to_gml(),
+    ///     "<gml:Polygon>\
+    ///     <gml:exterior><gml:LinearRing><gml:posList>0 0 3 0 3 3 0 3 0 0</gml:posList></gml:LinearRing></gml:exterior>\
+    ///     <gml:interior><gml:LinearRing><gml:posList>1 1 1 2 2 2 2 1 1 1</gml:posList></gml:LinearRing></gml:interior>\
+    ///     </gml:Polygon>",
+    /// );
+    /// ```
+    fn to_gml_with(&self, order: AxisOrder) -> String {
+        let rings = self
+            .iter()
+            .enumerate()
+            .map(|(index, ring)| {
+                let element = if index == 0 { "exterior" } else { "interior" };
+                format!(
+                    "<gml:{0}><gml:LinearRing><gml:posList>{1}</gml:posList></gml:LinearRing></gml:{0}>",
+                    element,
+                    positions(ring, order)
+                )
+            })
+            .join("");
+        format!("<gml:Polygon>{}</gml:Polygon>", rings)
+    }
+}
+
+impl ToGml for MultiPoint {
+    fn to_gml_with(&self, order: AxisOrder) -> String {
+        let members = self
+            .iter()
+            .map(|point| {
+                format!(
+                    "<gml:pointMember>{}</gml:pointMember>",
+                    point.to_gml_with(order)
+                )
+            })
+            .join("");
+        format!("<gml:MultiPoint>{}</gml:MultiPoint>", members)
+    }
+}
+
+impl ToGml for MultiLineString {
+    /// A `MultiLineString` is written as a `gml:MultiCurve`.
+    fn to_gml_with(&self, order: AxisOrder) -> String {
+        let members = self
+            .iter()
+            .map(|line_string| {
+                format!(
+                    "<gml:curveMember>{}</gml:curveMember>",
+                    line_string.to_gml_with(order)
+                )
+            })
+            .join("");
+        format!("<gml:MultiCurve>{}</gml:MultiCurve>", members)
+    }
+}
+
+impl ToGml for MultiPolygon {
+    /// A `MultiPolygon` is written as a `gml:MultiSurface`.
+    fn to_gml_with(&self, order: AxisOrder) -> String {
+        let members = self
+            .iter()
+            .map(|polygon| {
+                format!(
+                    "<gml:surfaceMember>{}</gml:surfaceMember>",
+                    polygon.to_gml_with(order)
+                )
+            })
+            .join("");
+        format!("<gml:MultiSurface>{}</gml:MultiSurface>", members)
+    }
+}
+
+impl ToGml for Geometry {
+    fn to_gml_with(&self, order: AxisOrder) -> String {
+        match self {
+            Geometry::Point(point) => point.to_gml_with(order),
+            Geometry::MultiPoint(multi_point) => multi_point.to_gml_with(order),
+            Geometry::LineString(line_string) => line_string.to_gml_with(order),
+            Geometry::MultiLineString(multi_line_string) => multi_line_string.to_gml_with(order),
+            Geometry::Polygon(polygon) => polygon.to_gml_with(order),
+            Geometry::MultiPolygon(multi_polygon) => multi_polygon.to_gml_with(order),
+        }
+    }
+}
@@ -1,5 +1,11 @@
+use std::fmt;
+
 use num_traits::{self, NumCast};
 
+use crate::error::GeometryError;
+use crate::polygon::Polygon;
+use crate::rect::Rect;
+
 /// Convert a vector of two-item arrays of generics that implement `NumCast` into a vector of two-item arrays of floats.
 ///
 /// Examples:
@@ -12,6 +18,11 @@ use num_traits::{self, NumCast};
 ///
 /// assert_eq!(output, expected)
 /// ```
+///
+/// # Panics
+///
+/// Panics if a value cannot be represented as `f64`. Use [`try_get_float_coordinates`] to
+/// handle that case instead.
 pub fn get_float_coordinates<T: NumCast>(coordinates: Vec<[T; 2]>) -> Vec<[f64; 2]> {
     let float_coordinates: Vec<[f64; 2]> = coordinates
         .into_iter()
@@ -22,6 +33,61 @@ pub fn get_float_coordinates<T: NumCast>(coordinates: Vec<[T; 2]>) -> Vec<[f64;
     float_coordinates
 }
 
+/// Convert a vector of two-item arrays of generics that implement `NumCast` into a vector of
+/// two-item arrays of floats, returning an error rather than panicking if a value cannot be
+/// represented as `f64`.
+///
+/// Examples:
+///
+/// ```
+/// use auto_gis_with_rust::error::GeometryError;
+/// use auto_gis_with_rust::helpers::try_get_float_coordinates;
+/// use num_traits::{NumCast, ToPrimitive};
+///
+/// // A number type that only converts to `f64` when it is non-negative.
+/// #[derive(Clone, Copy)]
+/// struct Natural(i64);
+///
+/// impl ToPrimitive for Natural {
+///     fn to_i64(&self) -> Option<i64> {
+///         Some(self.0)
+///     }
+///     fn to_u64(&self) -> Option<u64> {
+///         None
+///     }
+///     fn to_f64(&self) -> Option<f64> {
+///         (self.0 >= 0).then_some(self.0 as f64)
+///     }
+/// }
+///
+/// impl NumCast for Natural {
+///     fn from<T: ToPrimitive>(value: T) -> Option<Self> {
+///         value.to_i64().map(Natural)
+///     }
+/// }
+///
+/// assert_eq!(
+///     try_get_float_coordinates(vec![[0, 0], [0, 1]]).unwrap(),
+///     vec![[0., 0.], [0., 1.]],
+/// );
+/// assert!(matches!(
+///     try_get_float_coordinates(vec![[Natural(0), Natural(1)], [Natural(2), Natural(-3)]]),
+///     Err(GeometryError::InvalidCoordinate(1)),
+/// ));
+/// ```
+pub fn try_get_float_coordinates<T: NumCast>(
+    coordinates: Vec<[T; 2]>,
+) -> Result<Vec<[f64; 2]>, GeometryError> {
+    coordinates
+        .into_iter()
+        .enumerate()
+        .map(|(index, [x, y])| match (x.to_f64(), y.to_f64()) {
+            (Some(x), Some(y)) => Ok([x, y]),
+            _ => Err(GeometryError::InvalidCoordinate(index)),
+        })
+        .collect()
+}
+
 #[macro_export]
 macro_rules! implement_deref {
     ($type:ty, $target:ty) => {
@@ -34,3 +100,132 @@ macro_rules! implement_deref {
         }
     };
 }
+
+/// The z-component of the cross product of the vectors `origin -> a` and `origin -> b`.
+///
+/// Positive if `a`, `b` turn counter-clockwise around `origin`, negative if they turn
+/// clockwise and zero if the three coordinates are collinear.
+pub(crate) fn cross_product(origin: [f64; 2], a: [f64; 2], b: [f64; 2]) -> f64 {
+    (a[0] - origin[0]) * (b[1] - origin[1]) - (a[1] - origin[1]) * (b[0] - origin[0])
+}
+
+/// The vertices of the convex hull of `coordinates` in counter-clockwise order, starting
+/// from the lowest-leftmost vertex and without repeating it at the end.
+///
+/// Uses Andrew's monotone chain algorithm. Collinear vertices are dropped.
+pub(crate) fn convex_hull(coordinates: &[[f64; 2]]) -> Vec<[f64; 2]> {
+    let mut sorted = coordinates.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    sorted.dedup();
+    if sorted.len() < 3 {
+        return sorted;
+    }
+
+    let mut hull: Vec<[f64; 2]> = Vec::with_capacity(sorted.len() * 2);
+    for pass in [sorted.clone(), sorted.into_iter().rev().collect()] {
+        let start = hull.len();
+        for coordinate in pass {
+            while hull.len() >= start + 2
+                && cross_product(hull[hull.len() - 2], hull[hull.len() - 1], coordinate) <= 0.
+            {
+                hull.pop();
+            }
+            hull.push(coordinate);
+        }
+        hull.pop();
+    }
+    hull
+}
+
+/// The convex hull of `coordinates` as a `Polygon`, or `GeometryError::TooFewCoords` if
+/// there are fewer than three non-collinear coordinates.
+pub(crate) fn convex_hull_polygon(coordinates: &[[f64; 2]]) -> Result<Polygon, GeometryError> {
+    let hull = convex_hull(coordinates);
+    if hull.len() < 3 {
+        return Err(GeometryError::TooFewCoords(hull.len()));
+    }
+    Polygon::new(vec![hull])
+}
+
+/// The signed area enclosed by a closed ring of `coordinates`: positive if the ring runs
+/// counter-clockwise and negative if it runs clockwise.
+pub(crate) fn signed_area(coordinates: &[[f64; 2]]) -> f64 {
+    coordinates
+        .windows(2)
+        .map(|pair| pair[0][0] * pair[1][1] - pair[1][0] * pair[0][1])
+        .sum::<f64>()
+        / 2.
+}
+
+/// A hashable key identifying the exact value of `coordinate`, treating `-0.0` and `0.0`
+/// as equal.
+pub(crate) fn coordinate_key(coordinate: [f64; 2]) -> [u64; 2] {
+    coordinate.map(|value| (value + 0.).to_bits())
+}
+
+/// Whether `coordinates` and `other` have the same length and each pair of corresponding
+/// coordinates is no further apart than `tolerance`.
+pub(crate) fn coordinates_equal(
+    coordinates: &[[f64; 2]],
+    other: &[[f64; 2]],
+    tolerance: f64,
+) -> bool {
+    coordinates.len() == other.len()
+        && coordinates.iter().zip(other).all(|(coordinate, other)| {
+            (coordinate[0] - other[0]).hypot(coordinate[1] - other[1]) <= tolerance
+        })
+}
+
+/// Whether the closed rings `ring` and `other` visit the same coordinates, within
+/// `tolerance`, regardless of where each ring starts and which direction it runs in.
+pub(crate) fn rings_equal_normalized(
+    ring: &[[f64; 2]],
+    other: &[[f64; 2]],
+    tolerance: f64,
+) -> bool {
+    if ring.len() != other.len() || ring.is_empty() {
+        return ring.len() == other.len();
+    }
+    let open = &other[..other.len() - 1];
+    let reversed: Vec<[f64; 2]> = open.iter().rev().copied().collect();
+    [open.to_vec(), reversed].iter().any(|candidate| {
+        (0..candidate.len()).any(|start| {
+            let mut rotated: Vec<[f64; 2]> = candidate[start..]
+                .iter()
+                .chain(&candidate[..start])
+                .copied()
+                .collect();
+            rotated.push(rotated[0]);
+            coordinates_equal(ring, &rotated, tolerance)
+        })
+    })
+}
+
+/// Format a geometry for `Debug`: a summary of its size and envelope normally, or every
+/// coordinate, as a derived implementation would, in the alternate `{:#?}` form.
+pub(crate) fn debug_geometry<T: fmt::Debug>(
+    f: &mut fmt::Formatter<'_>,
+    name: &str,
+    counts: &[(&str, usize)],
+    envelope: Option<Rect>,
+    contents: &T,
+) -> fmt::Result {
+    if f.alternate() {
+        return f.debug_tuple(name).field(contents).finish();
+    }
+    let mut summary = f.debug_struct(name);
+    for (field, count) in counts {
+        summary.field(field, count);
+    }
+    match envelope {
+        Some(rect) => summary.field(
+            "bbox",
+            &[
+                [rect.min().x(), rect.min().y()],
+                [rect.max().x(), rect.max().y()],
+            ],
+        ),
+        None => summary.field("bbox", &None::<()>),
+    };
+    summary.finish()
+}
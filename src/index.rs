@@ -0,0 +1,330 @@
+use std::collections::HashMap;
+
+use crate::geometry::Geometry;
+use crate::point::Point;
+use crate::rect::Rect;
+use crate::traits::Envelope;
+
+/// The most entries a node of an [`RTree`] holds.
+const NODE_CAPACITY: usize = 16;
+
+/// The most cells of a [`GridIndex`] one envelope is added to. Larger envelopes are kept
+/// aside and checked on every query instead.
+const MAX_CELLS_PER_ENVELOPE: i128 = 1024;
+
+/// An R-tree over the envelopes of a slice of geometries, answering which of them have an
+/// envelope intersecting a query `Rect`.
+///
+/// The tree is bulk loaded once with the Sort-Tile-Recursive algorithm and cannot be
+/// modified afterwards. Empty geometries have no envelope and are never returned.
+///
+/// # Examples:
+///
+/// ```
+/// use auto_gis_with_rust::index::RTree;
+/// use auto_gis_with_rust::point::Point;
+/// use auto_gis_with_rust::rect::Rect;
+///
+/// let points = vec![Point::new(0, 0), Point::new(5, 5), Point::new(1, 2)];
+/// let tree = RTree::new(&points);
+///
+/// assert_eq!(tree.query(&Rect::new([0, 0], [2, 2])), vec![0, 2]);
+/// assert!(tree.query(&Rect::new([6, 6], [7, 7])).is_empty());
+/// ```
+#[derive(Debug, Clone)]
+pub struct RTree {
+    root: Option<(Rect, Node)>,
+}
+
+#[derive(Debug, Clone)]
+enum Node {
+    Leaf(Vec<(Rect, usize)>),
+    Branch(Vec<(Rect, Node)>),
+}
+
+impl RTree {
+    /// Build an `RTree` over the envelopes of `geometries`, identifying each by its index.
+    pub fn new<G: Envelope>(geometries: &[G]) -> Self {
+        let entries: Vec<(Rect, usize)> = geometries
+            .iter()
+            .enumerate()
+            .filter_map(|(index, geometry)| Some((geometry.envelope()?, index)))
+            .collect();
+        if entries.is_empty() {
+            return RTree { root: None };
+        }
+        let mut level: Vec<(Rect, Node)> = pack(entries)
+            .into_iter()
+            .map(|(rect, group)| (rect, Node::Leaf(group)))
+            .collect();
+        while level.len() > 1 {
+            level = pack(level)
+                .into_iter()
+                .map(|(rect, group)| (rect, Node::Branch(group)))
+                .collect();
+        }
+        RTree { root: level.pop() }
+    }
+
+    /// The indices of the geometries whose envelopes intersect `rect`, in ascending order.
+    pub fn query(&self, rect: &Rect) -> Vec<usize> {
+        let mut found = Vec::new();
+        let mut stack: Vec<&(Rect, Node)> = self.root.iter().collect();
+        while let Some((node_rect, node)) = stack.pop() {
+            if !node_rect.intersects(rect) {
+                continue;
+            }
+            match node {
+                Node::Leaf(entries) => found.extend(
+                    entries
+                        .iter()
+                        .filter(|(entry_rect, _)| entry_rect.intersects(rect))
+                        .map(|(_, index)| *index),
+                ),
+                Node::Branch(children) => stack.extend(children),
+            }
+        }
+        found.sort_unstable();
+        found
+    }
+}
+
+/// A uniform grid over the envelopes of a slice of geometries, answering which of them have
+/// an envelope intersecting a query `Rect`.
+///
+/// Each envelope is added to every square cell of side `cell_size` it overlaps, so a query
+/// only inspects the cells it overlaps. This is quicker to build than an [`RTree`] and
+/// works well when the geometries are spread fairly evenly and are small compared with the
+/// cells. An envelope overlapping more than 1024 cells is not added to the cells but
+/// checked against every query, so a few large geometries don't fill the grid. Empty
+/// geometries have no envelope and are never returned.
+///
+/// # Examples:
+///
+/// ```
+/// use auto_gis_with_rust::index::GridIndex;
+/// use auto_gis_with_rust::point::Point;
+/// use auto_gis_with_rust::rect::Rect;
+/// use auto_gis_with_rust::traits::Envelope;
+///
+/// // A small linear congruential generator, to scatter points reproducibly.
+/// let mut state = 12345u64;
+/// let mut random = || {
+///     state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+///     (state >> 11) as f64 / (1u64 << 53) as f64 * 100.
+/// };
+/// let points: Vec<Point> = (0..500).map(|_| Point::new(random(), random())).collect();
+/// let grid = GridIndex::new(&points, 7.5);
+///
+/// for _ in 0..50 {
+///     let query = Rect::new([random() - 10., random() - 10.], [random(), random()]);
+///     let brute_force: Vec<usize> = (0..points.len())
+///         .filter(|index| points[*index].envelope().unwrap().intersects(&query))
+///         .collect();
+///     assert_eq!(grid.query_bbox(&query), brute_force);
+/// }
+/// ```
+///
+/// Queries and envelopes far larger than the cells only visit the cells in use.
+///
+/// ```
+/// use auto_gis_with_rust::index::GridIndex;
+/// use auto_gis_with_rust::rect::Rect;
+///
+/// let squares = [
+///     Rect::new([0, 0], [1, 1]),
+///     Rect::new([5, 5], [6, 6]),
+///     Rect::new([-1e15, -1e15], [1e15, 1e15]),
+/// ]
+/// .map(|rect| rect.to_polygon());
+/// let grid = GridIndex::new(&squares, 0.1);
+///
+/// assert_eq!(grid.query_bbox(&Rect::new([-1e20, 0.], [1e20, 1.])), vec![0, 2]);
+/// assert_eq!(grid.query_bbox(&Rect::new([f64::MIN, f64::MIN], [f64::MAX, f64::MAX])), vec![0, 1, 2]);
+/// assert_eq!(grid.query_bbox(&Rect::new([5.5, 5.5], [5.6, 5.6])), vec![1, 2]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct GridIndex {
+    cell_size: f64,
+    envelopes: Vec<Option<Rect>>,
+    cells: HashMap<(i64, i64), Vec<usize>>,
+    extent: Option<([i64; 2], [i64; 2])>,
+    oversized: Vec<usize>,
+}
+
+impl GridIndex {
+    /// Build a `GridIndex` with square cells of side `cell_size` over the envelopes of
+    /// `geometries`, identifying each by its index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cell_size` is not a positive, finite number.
+    pub fn new<G: Envelope>(geometries: &[G], cell_size: f64) -> Self {
+        assert!(
+            cell_size > 0. && cell_size.is_finite(),
+            "cell size must be positive and finite, found {cell_size}"
+        );
+        let mut grid = GridIndex {
+            cell_size,
+            envelopes: geometries.iter().map(Envelope::envelope).collect(),
+            cells: HashMap::new(),
+            extent: None,
+            oversized: Vec::new(),
+        };
+        for (index, envelope) in grid.envelopes.iter().enumerate() {
+            if let Some(envelope) = envelope {
+                let (min, max) = grid.cell_range(envelope);
+                if cell_count(min, max) > MAX_CELLS_PER_ENVELOPE {
+                    grid.oversized.push(index);
+                    continue;
+                }
+                for column in min[0]..=max[0] {
+                    for row in min[1]..=max[1] {
+                        grid.cells.entry((column, row)).or_default().push(index);
+                    }
+                }
+                grid.extent = Some(match grid.extent {
+                    Some((extent_min, extent_max)) => (
+                        [extent_min[0].min(min[0]), extent_min[1].min(min[1])],
+                        [extent_max[0].max(max[0]), extent_max[1].max(max[1])],
+                    ),
+                    None => (min, max),
+                });
+            }
+        }
+        grid
+    }
+
+    /// The side of the square cells of this `GridIndex`.
+    pub fn cell_size(&self) -> f64 {
+        self.cell_size
+    }
+
+    /// The indices of the geometries whose envelopes intersect `rect`, in ascending order.
+    pub fn query_bbox(&self, rect: &Rect) -> Vec<usize> {
+        let mut candidates = self.oversized.clone();
+        if let Some((extent_min, extent_max)) = self.extent {
+            let (min, max) = self.cell_range(rect);
+            let [min_column, min_row] = [min[0].max(extent_min[0]), min[1].max(extent_min[1])];
+            let [max_column, max_row] = [max[0].min(extent_max[0]), max[1].min(extent_max[1])];
+            if cell_count([min_column, min_row], [max_column, max_row]) > self.cells.len() as i128 {
+                candidates.extend(
+                    self.cells
+                        .iter()
+                        .filter(|((column, row), _)| {
+                            (min_column..=max_column).contains(column)
+                                && (min_row..=max_row).contains(row)
+                        })
+                        .flat_map(|(_, indices)| indices.iter().copied()),
+                );
+            } else {
+                candidates.extend(
+                    (min_column..=max_column)
+                        .flat_map(|column| (min_row..=max_row).map(move |row| (column, row)))
+                        .filter_map(|cell| self.cells.get(&cell))
+                        .flat_map(|indices| indices.iter().copied()),
+                );
+            }
+        }
+        let mut found: Vec<usize> = candidates
+            .into_iter()
+            .filter(|index| {
+                self.envelopes[*index].is_some_and(|envelope| envelope.intersects(rect))
+            })
+            .collect();
+        found.sort_unstable();
+        found.dedup();
+        found
+    }
+
+    /// The columns and rows of the first and last cells overlapped by `rect`.
+    fn cell_range(&self, rect: &Rect) -> ([i64; 2], [i64; 2]) {
+        let cell = |point: Point| {
+            [point.x(), point.y()].map(|value| (value / self.cell_size).floor() as i64)
+        };
+        (cell(rect.min()), cell(rect.max()))
+    }
+}
+
+/// The number of cells from `min` to `max`, inclusive, or 0 if the range is empty.
+fn cell_count(min: [i64; 2], max: [i64; 2]) -> i128 {
+    let span = |axis: usize| (max[axis] as i128 - min[axis] as i128 + 1).max(0);
+    span(0) * span(1)
+}
+
+/// Group `entries` into nodes of at most [`NODE_CAPACITY`] entries with Sort-Tile-Recursive
+/// packing, returning each group with the `Rect` bounding it.
+///
+/// The entries are sorted by the x coordinate of their centers and cut into vertical
+/// slices, and each slice is sorted by y and cut into groups.
+fn pack<T>(mut entries: Vec<(Rect, T)>) -> Vec<(Rect, Vec<(Rect, T)>)> {
+    let center = |rect: &Rect, axis: usize| (rect.min()[axis] + rect.max()[axis]) / 2.;
+    let groups = entries.len().div_ceil(NODE_CAPACITY);
+    let slice_size = NODE_CAPACITY * (groups as f64).sqrt().ceil() as usize;
+    entries.sort_by(|(a, _), (b, _)| center(a, 0).total_cmp(&center(b, 0)));
+    let mut packed = Vec::with_capacity(groups);
+    while !entries.is_empty() {
+        let rest = entries.split_off(slice_size.min(entries.len()));
+        let mut slice = std::mem::replace(&mut entries, rest);
+        slice.sort_by(|(a, _), (b, _)| center(a, 1).total_cmp(&center(b, 1)));
+        while !slice.is_empty() {
+            let rest = slice.split_off(NODE_CAPACITY.min(slice.len()));
+            let group = std::mem::replace(&mut slice, rest);
+            let rect = group[1..]
+                .iter()
+                .fold(group[0].0, |bounds, (rect, _)| bounds.union(rect));
+            packed.push((rect, group));
+        }
+    }
+    packed
+}
+
+/// Every pair of indices into `left` and `right` whose geometries have intersecting
+/// envelopes, ordered by the index into `left` and then into `right`.
+///
+/// An [`RTree`] is built over `right` and queried with the envelope of each geometry in
+/// `left`. Empty geometries match nothing. The pairs are only candidates, so test them with
+/// an exact predicate if the geometries themselves must intersect.
+///
+/// # Examples:
+///
+/// ```
+/// use auto_gis_with_rust::generators::{point_grid, regular_polygon};
+/// use auto_gis_with_rust::geometry::Geometry;
+/// use auto_gis_with_rust::index::sjoin;
+/// use auto_gis_with_rust::point::Point;
+/// use auto_gis_with_rust::traits::Envelope;
+///
+/// let left: Vec<Geometry> = point_grid(Point::new(0, 0), 5, 4, 7., 9.)
+///     .iter()
+///     .map(|center| Geometry::from(regular_polygon(*center, 3., 6).unwrap()))
+///     .collect();
+/// let right: Vec<Geometry> = point_grid(Point::new(-2, -2), 20, 20, 2., 2.)
+///     .iter()
+///     .map(|point| Geometry::from(*point))
+///     .collect();
+///
+/// let mut brute_force = Vec::new();
+/// for (i, a) in left.iter().enumerate() {
+///     for (j, b) in right.iter().enumerate() {
+///         if a.envelope().unwrap().intersects(&b.envelope().unwrap()) {
+///             brute_force.push((i, j));
+///         }
+///     }
+/// }
+///
+/// assert!(!brute_force.is_empty());
+/// assert_eq!(sjoin(&left, &right), brute_force);
+/// ```
+pub fn sjoin(left: &[Geometry], right: &[Geometry]) -> Vec<(usize, usize)> {
+    let tree = RTree::new(right);
+    left.iter()
+        .enumerate()
+        .filter_map(|(index, geometry)| Some((index, geometry.envelope()?)))
+        .flat_map(|(index, rect)| {
+            tree.query(&rect)
+                .into_iter()
+                .map(move |other_index| (index, other_index))
+        })
+        .collect()
+}
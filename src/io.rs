@@ -0,0 +1,77 @@
+use std::fmt::Display;
+use std::io::{BufRead, BufWriter, Write};
+
+use crate::error::GeometryError;
+use crate::geometry::Geometry;
+
+/// Lazily parse one WKT geometry per line of `reader`.
+///
+/// Blank lines are skipped. A line that fails to parse, or fails to be read, yields an
+/// `Err` without ending the iteration, so the remaining lines can still be processed.
+///
+/// # Examples:
+///
+/// ```
+/// use std::io::Cursor;
+///
+/// use auto_gis_with_rust::io::read_wkt_lines;
+///
+/// let input = Cursor::new(
+///     "POINT (0 1)\nLINESTRING (0 0, 1 1)\n\nNOT WKT\nMULTIPOLYGON (((0 0, 1 0, 1 1, 0 0)))\n",
+/// );
+/// let geometries: Vec<_> = read_wkt_lines(input).collect();
+///
+/// assert_eq!(geometries.len(), 4);
+/// assert_eq!(geometries[0].as_ref().unwrap().to_string(), "POINT (0 1)");
+/// assert_eq!(geometries[1].as_ref().unwrap().to_string(), "LINESTRING (0 0, 1 1)");
+/// assert!(geometries[2].is_err());
+/// assert_eq!(
+///     geometries[3].as_ref().unwrap().to_string(),
+///     "MULTIPOLYGON (((0 0, 1 0, 1 1, 0 0)))",
+/// );
+/// ```
+pub fn read_wkt_lines<R: BufRead>(
+    reader: R,
+) -> impl Iterator<Item = Result<Geometry, GeometryError>> {
+    reader.lines().filter_map(|line| match line {
+        Ok(line) if line.trim().is_empty() => None,
+        Ok(line) => Some(line.parse()),
+        Err(error) => Some(Err(GeometryError::from(error))),
+    })
+}
+
+/// Write `geometries` to `writer` as WKT, one geometry per line.
+///
+/// Writes are buffered and flushed before returning, and the first IO error is returned.
+///
+/// # Examples:
+///
+/// ```
+/// use auto_gis_with_rust::geometry::Geometry;
+/// use auto_gis_with_rust::io::{read_wkt_lines, write_wkt};
+/// use auto_gis_with_rust::line_string::LineString;
+/// use auto_gis_with_rust::point::Point;
+///
+/// let geometries = vec![
+///     Geometry::from(Point::new(0, 1)),
+///     Geometry::from(LineString::new(vec![[0.5, 0.], [1., 1.]]).unwrap()),
+/// ];
+/// let mut buffer = Vec::new();
+/// write_wkt(&mut buffer, &geometries).unwrap();
+///
+/// assert_eq!(String::from_utf8(buffer.clone()).unwrap(), "POINT (0 1)\nLINESTRING (0.5 0, 1 1)\n");
+///
+/// let read: Vec<Geometry> = read_wkt_lines(buffer.as_slice()).map(Result::unwrap).collect();
+///
+/// assert_eq!(read, geometries);
+/// ```
+pub fn write_wkt<W: Write, I: IntoIterator<Item = G>, G: Display>(
+    writer: W,
+    geometries: I,
+) -> std::io::Result<()> {
+    let mut writer = BufWriter::new(writer);
+    for geometry in geometries {
+        writeln!(writer, "{}", geometry)?;
+    }
+    writer.flush()
+}
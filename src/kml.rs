@@ -0,0 +1,148 @@
+use itertools::Itertools;
+
+use crate::geometry::Geometry;
+use crate::line_string::{LineString, MultiLineString};
+use crate::point::{MultiPoint, Point};
+use crate::polygon::{MultiPolygon, Polygon};
+
+/// Serializes a geometry as a KML geometry element.
+pub trait ToKml {
+    /// This geometry as KML, with coordinates written as `lon,lat` tuples.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::kml::ToKml;
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// assert_eq!(
+    ///     Point::new(4.9, 52.4).to_kml(),
+    ///     "<Point><coordinates>4.9,52.4</coordinates></Point>",
+    /// );
+    /// ```
+    fn to_kml(&self) -> String;
+}
+
+fn coordinates(coordinates: &[[f64; 2]]) -> String {
+    format!(
+        "<coordinates>{}</coordinates>",
+        coordinates
+            .iter()
+            .map(|[longitude, latitude]| format!("{},{}", longitude, latitude))
+            .join(" ")
+    )
+}
+
+fn multi_geometry<T: ToKml>(members: &[T]) -> String {
+    format!(
+        "<MultiGeometry>{}</MultiGeometry>",
+        members.iter().map(|member| member.to_kml()).join("")
+    )
+}
+
+impl ToKml for Point {
+    fn to_kml(&self) -> String {
+        format!("<Point>{}</Point>", coordinates(&[[self.x(), self.y()]]))
+    }
+}
+
+impl ToKml for LineString {
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::kml::ToKml;
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let line_string = LineString::new(vec![[0, 0], [1, 2]]).unwrap();
+    ///
+    /// assert_eq!(
+    ///     line_string.to_kml(),
+    ///     "<LineString><coordinates>0,0 1,2</coordinates></LineString>",
+    /// );
+    /// ```
+    fn to_kml(&self) -> String {
+        format!("<LineString>{}</LineString>", coordinates(self))
+    }
+}
+
+impl ToKml for Polygon {
+    /// The first ring is written as the `outerBoundaryIs` and any others as
+    /// `innerBoundaryIs`.
+    fn to_kml(&self) -> String {
+        let rings = self
+            .iter()
+            .enumerate()
+            .map(|(index, ring)| {
+                let element = if index == 0 {
+                    "outerBoundaryIs"
+                } else {
+                    "innerBoundaryIs"
+                };
+                format!(
+                    "<{0}><LinearRing>{1}</LinearRing></{0}>",
+                    element,
+                    coordinates(ring)
+                )
+            })
+            .join("");
+        format!("<Polygon>{}</Polygon>", rings)
+    }
+}
+
+impl ToKml for MultiPoint {
+    fn to_kml(&self) -> String {
+        multi_geometry(self)
+    }
+}
+
+impl ToKml for MultiLineString {
+    fn to_kml(&self) -> String {
+        multi_geometry(self)
+    }
+}
+
+impl ToKml for MultiPolygon {
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::kml::ToKml;
+    /// use auto_gis_with_rust::polygon::{MultiPolygon, Polygon};
+    ///
+    /// let multi_polygon = MultiPolygon::new(vec![
+    ///     Polygon::new(vec![
+    ///         vec![[0, 0], [3, 0], [3, 3], [0, 3]],
+    ///         vec![[1, 1], [1, 2], [2, 2], [2, 1]],
+    ///     ]).unwrap(),
+    ///     Polygon::new(vec![vec![[5, 5], [6, 5], [6, 6]]]).unwrap(),
+    /// ]);
+    ///
+    /// assert_eq!(
+    ///     multi_polygon.to_kml(),
+    ///     "<MultiGeometry>\
+    ///     <Polygon>\
+    ///     <outerBoundaryIs><LinearRing><coordinates>0,0 3,0 3,3 0,3 0,0</coordinates></LinearRing></outerBoundaryIs>\
+    ///     <innerBoundaryIs><LinearRing><coordinates>1,1 1,2 2,2 2,1 1,1</coordinates></LinearRing></innerBoundaryIs>\
+    ///     </Polygon>\
+    ///     <Polygon>\
+    ///     <outerBoundaryIs><LinearRing><coordinates>5,5 6,5 6,6 5,5</coordinates></LinearRing></outerBoundaryIs>\
+    ///     </Polygon>\
+    ///     </MultiGeometry>",
+    /// );
+    /// ```
+    fn to_kml(&self) -> String {
+        multi_geometry(self)
+    }
+}
+
+impl ToKml for Geometry {
+    fn to_kml(&self) -> String {
+        match self {
+            Geometry::Point(point) => point.to_kml(),
+            Geometry::MultiPoint(multi_point) => multi_point.to_kml(),
+            Geometry::LineString(line_string) => line_string.to_kml(),
+            Geometry::MultiLineString(multi_line_string) => multi_line_string.to_kml(),
+            Geometry::Polygon(polygon) => polygon.to_kml(),
+            Geometry::MultiPolygon(multi_polygon) => multi_polygon.to_kml(),
+        }
+    }
+}
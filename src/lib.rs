@@ -1,6 +1,105 @@
+pub mod affine;
+pub mod buffer;
 pub mod error;
+pub mod generators;
+#[cfg(feature = "geo-interop")]
+pub mod geo_interop;
+pub mod geohash;
+pub mod geometry;
+pub mod gml;
 pub mod helpers;
+pub mod index;
+pub mod io;
+pub mod kml;
 pub mod line_string;
+pub mod overlay;
 pub mod point;
 pub mod polygon;
+pub mod polyline;
+pub mod predicates;
+pub mod prepared;
+pub mod projection;
+#[cfg(feature = "rand")]
+pub mod random;
+pub mod rect;
+pub mod topology;
 pub mod traits;
+pub mod units;
+pub mod validity;
+pub mod wkb;
+pub mod wkt;
+
+/// Construct a geometry from its coordinates with less boilerplate.
+///
+/// `point` and `multipoint` expand to infallible constructors, while `linestring`,
+/// `multilinestring`, `polygon` and `multipolygon` expand to the corresponding
+/// fallible constructor and so evaluate to a `Result`.
+///
+/// # Examples:
+///
+/// ```
+/// use auto_gis_with_rust::geometry;
+///
+/// let point = geometry!(point(0, 1));
+/// let multi_point = geometry!(multipoint [[0, 0], [1, 0]]);
+///
+/// assert_eq!("POINT (0 1)", point.to_string());
+/// assert_eq!("MULTIPOINT ((0 0), (1 0))", multi_point.to_string());
+/// ```
+///
+/// ```
+/// use auto_gis_with_rust::error::GeometryError;
+/// use auto_gis_with_rust::geometry;
+///
+/// # fn main() -> Result<(), GeometryError> {
+/// let line_string = geometry!(linestring [[0, 0], [1, 1]])?;
+/// let polygon = geometry!(polygon [[[0, 0], [0, 1], [1, 1]]])?;
+///
+/// assert_eq!("LINESTRING (0 0, 1 1)", line_string.to_string());
+/// assert_eq!("POLYGON ((0 0, 0 1, 1 1, 0 0))", polygon.to_string());
+/// # Ok(())
+/// # }
+/// ```
+///
+/// ```
+/// use auto_gis_with_rust::geometry;
+///
+/// let multi_line_string = geometry!(multilinestring [[[0, 0], [1, 0]], [[1, 1], [0, 1]]]).unwrap();
+/// let multi_polygon = geometry!(multipolygon [[[[0, 0], [0, 1], [1, 1]]]]).unwrap();
+///
+/// assert_eq!("MULTILINESTRING ((0 0, 1 0), (1 1, 0 1))", multi_line_string.to_string());
+/// assert_eq!("MULTIPOLYGON (((0 0, 0 1, 1 1, 0 0)))", multi_polygon.to_string());
+/// ```
+///
+/// Constructors that validate their input propagate the error.
+///
+/// ```
+/// use auto_gis_with_rust::geometry;
+///
+/// assert!(geometry!(linestring [[0, 0]]).is_err());
+/// ```
+#[macro_export]
+macro_rules! geometry {
+    (point($x:expr, $y:expr $(,)?)) => {
+        $crate::point::Point::new($x, $y)
+    };
+    (multipoint [$($coordinates:tt),* $(,)?]) => {
+        $crate::point::MultiPoint::from(vec![$($coordinates),*])
+    };
+    (linestring [$($coordinates:tt),* $(,)?]) => {
+        $crate::line_string::LineString::new(vec![$($coordinates),*])
+    };
+    (multilinestring [$([$($coordinates:tt),* $(,)?]),* $(,)?]) => {
+        <$crate::line_string::MultiLineString as ::std::convert::TryFrom<_>>::try_from(
+            vec![$(vec![$($coordinates),*]),*],
+        )
+    };
+    (polygon [$([$($coordinates:tt),* $(,)?]),* $(,)?]) => {
+        $crate::polygon::Polygon::new(vec![$(vec![$($coordinates),*]),*])
+    };
+    (multipolygon [$([$([$($coordinates:tt),* $(,)?]),* $(,)?]),* $(,)?]) => {
+        <$crate::polygon::MultiPolygon as ::std::convert::TryFrom<_>>::try_from(
+            vec![$(vec![$(vec![$($coordinates),*]),*]),*],
+        )
+    };
+}
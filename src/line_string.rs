@@ -1,14 +1,29 @@
-use std::{convert::TryFrom, fmt, ops::Deref};
+use std::{
+    convert::TryFrom,
+    fmt,
+    ops::{Deref, Index},
+    slice::SliceIndex,
+};
 
 use itertools::Itertools;
 use num_traits::NumCast;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+#[cfg(feature = "small-line-string")]
+use smallvec::SmallVec;
 
 use crate::error::GeometryError;
-use crate::point::Point;
-use crate::traits::{self, Curve, Geometry};
+use crate::geometry;
+use crate::point::{MultiPoint, Point};
+use crate::polygon::Polygon;
+use crate::rect::Rect;
+use crate::traits::{
+    self, ConvexHull, Curve, Dimensions, Envelope, EqualsExact, Geometry, GeometryType,
+    MapCoordinates,
+};
 use crate::{helpers, implement_deref};
 
-#[derive(Debug, PartialEq, PartialOrd)]
+#[derive(Debug, PartialEq, PartialOrd, Clone)]
 pub struct LineSegment([[f64; 2]; 2]);
 
 impl LineSegment {
@@ -73,6 +88,172 @@ impl LineSegment {
     pub fn y_length(&self) -> f64 {
         self.end_point().y() - self.start_point().y()
     }
+
+    /// The `Point` on this `LineSegment` closest to `point`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineSegment;
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// let line_segment = LineSegment::new([[0., 0.], [4., 0.]]);
+    ///
+    /// assert_eq!(line_segment.closest_point(&Point::new(1, 2)), Point::new(1, 0));
+    /// assert_eq!(line_segment.closest_point(&Point::new(6, 2)), Point::new(4, 0));
+    /// ```
+    pub fn closest_point(&self, point: &Point) -> Point {
+        let start = self.start_point();
+        let fraction = self.project(point);
+        Point::new(
+            start.x() + fraction * self.x_length(),
+            start.y() + fraction * self.y_length(),
+        )
+    }
+
+    /// The position along this `LineSegment` of its `Point` closest to `point`, as a
+    /// fraction of its length from `0.0` at the start `Point` to `1.0` at the end `Point`.
+    ///
+    /// This is where the perpendicular from `point` meets the line through the
+    /// `LineSegment`, clamped to the `LineSegment` itself, so that evaluating the
+    /// `LineSegment` at it gives [`closest_point`](LineSegment::closest_point). A
+    /// `LineSegment` with no length gives `0.0`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineSegment;
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// let line_segment = LineSegment::new([[0., 0.], [4., 0.]]);
+    ///
+    /// assert_eq!(line_segment.project(&Point::new(2, 3)), 0.5);
+    /// assert_eq!(line_segment.project(&Point::new(1, -1)), 0.25);
+    /// assert_eq!(line_segment.project(&Point::new(-2, 1)), 0.);
+    /// assert_eq!(line_segment.project(&Point::new(9, 0)), 1.);
+    /// ```
+    pub fn project(&self, point: &Point) -> f64 {
+        let start = self.start_point();
+        let x_length = self.x_length();
+        let y_length = self.y_length();
+        let squared_length = x_length.powi(2) + y_length.powi(2);
+        if squared_length == 0. {
+            return 0.;
+        }
+        let fraction = ((point.x() - start.x()) * x_length + (point.y() - start.y()) * y_length)
+            / squared_length;
+        fraction.clamp(0., 1.)
+    }
+
+    /// The shortest distance between `point` and this `LineSegment`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineSegment;
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// let line_segment = LineSegment::new([[0., 0.], [4., 0.]]);
+    ///
+    /// assert_eq!(line_segment.distance(&Point::new(1, 2)), 2.);
+    /// assert_eq!(line_segment.distance(&Point::new(7, 4)), 5.);
+    /// ```
+    pub fn distance(&self, point: &Point) -> f64 {
+        self.closest_point(point).distance(point)
+    }
+
+    /// The `Point` where this `LineSegment` meets `other`.
+    ///
+    /// Returns `None` if they do not meet, or if they are collinear and overlap along a
+    /// stretch rather than at a single `Point`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineSegment;
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// let line_segment_1 = LineSegment::new([[0., 0.], [2., 2.]]);
+    /// let line_segment_2 = LineSegment::new([[0., 2.], [2., 0.]]);
+    /// let line_segment_3 = LineSegment::new([[3., 0.], [3., 2.]]);
+    ///
+    /// assert_eq!(line_segment_1.intersection(&line_segment_2), Some(Point::new(1, 1)));
+    /// assert_eq!(line_segment_1.intersection(&line_segment_3), None);
+    /// ```
+    pub fn intersection(&self, other: &LineSegment) -> Option<Point> {
+        match self.intersection_coordinates(other)[..] {
+            [coordinate] => Some(Point::from(coordinate)),
+            _ => None,
+        }
+    }
+
+    /// The coordinates where this `LineSegment` meets `other`: none if they are disjoint,
+    /// one if they cross or touch, and the two ends of the shared stretch if they are
+    /// collinear and overlap.
+    ///
+    /// The result does not depend on which of the two `LineSegment`s is `self`, so the
+    /// same coordinates can be inserted into both.
+    pub(crate) fn intersection_coordinates(&self, other: &LineSegment) -> Vec<[f64; 2]> {
+        let (a, b) = if self.0 <= other.0 {
+            (self.0, other.0)
+        } else {
+            (other.0, self.0)
+        };
+        let a_start = helpers::cross_product(b[0], b[1], a[0]);
+        let a_end = helpers::cross_product(b[0], b[1], a[1]);
+        let b_start = helpers::cross_product(a[0], a[1], b[0]);
+        let b_end = helpers::cross_product(a[0], a[1], b[1]);
+
+        if a_start * a_end < 0. && b_start * b_end < 0. {
+            let fraction = b_start / (b_start - b_end);
+            return vec![[
+                b[0][0] + fraction * (b[1][0] - b[0][0]),
+                b[0][1] + fraction * (b[1][1] - b[0][1]),
+            ]];
+        }
+
+        let within = |segment: [[f64; 2]; 2], coordinate: [f64; 2]| {
+            (0..2).all(|axis| {
+                let low = segment[0][axis].min(segment[1][axis]);
+                let high = segment[0][axis].max(segment[1][axis]);
+                low <= coordinate[axis] && coordinate[axis] <= high
+            })
+        };
+        let mut coordinates = Vec::new();
+        for (turn, coordinate, segment) in [
+            (a_start, a[0], b),
+            (a_end, a[1], b),
+            (b_start, b[0], a),
+            (b_end, b[1], a),
+        ] {
+            if turn == 0. && within(segment, coordinate) && !coordinates.contains(&coordinate) {
+                coordinates.push(coordinate);
+            }
+        }
+        coordinates
+    }
+
+    /// The initial bearing in degrees from the start to the end of this `LineSegment`,
+    /// clockwise from north, as found by [`Point::bearing`].
+    ///
+    /// The ends are treated as longitude and latitude in degrees, and the bearing is that of
+    /// the great circle joining them, so it is only meaningful for geographic coordinates.
+    /// Away from the equator, a segment due east along a parallel starts out a little north
+    /// of east, because the great circle bulges towards the pole.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineSegment;
+    ///
+    /// assert_eq!(LineSegment::new([[0, 0], [10, 0]]).azimuth(), 90.);
+    /// assert_eq!(LineSegment::new([[0, 0], [0, -10]]).azimuth(), 180.);
+    /// assert!((LineSegment::new([[0, 60], [10, 60]]).azimuth() - 85.67).abs() < 0.01);
+    /// assert!((LineSegment::new([[10, -60], [0, -60]]).azimuth() - 265.67).abs() < 0.01);
+    /// ```
+    pub fn azimuth(&self) -> f64 {
+        Point::from(self[0]).bearing(&Point::from(self[1]))
+    }
 }
 
 implement_deref!(LineSegment, [[f64; 2]; 2]);
@@ -101,11 +282,12 @@ impl Geometry for LineSegment {
     /// let line_segment = LineSegment::new([[0., 0.], [4., 3.]]);
     /// let centroid = line_segment.centroid();
     ///
-    /// assert_eq!(centroid.to_string(), "POINT (2 1.5)")  
+    /// assert_eq!(centroid.to_string(), "POINT (2 1.5)");
+    /// assert_eq!(LineSegment::new([[1, 1], [3, 5]]).centroid().to_string(), "POINT (2 3)");
     /// ```
     fn centroid(&self) -> Point {
-        let x = self.x_length() / 2.;
-        let y = self.y_length() / 2.;
+        let x = self[0][0] + self.x_length() / 2.;
+        let y = self[0][1] + self.y_length() / 2.;
         Point::new(x, y)
     }
 
@@ -304,8 +486,101 @@ impl traits::LineString for LineSegment {
     }
 }
 
-#[derive(Debug, PartialEq, PartialOrd)]
-pub struct LineString(Vec<[f64; 2]>);
+impl Envelope for LineSegment {
+    fn envelope(&self) -> Option<Rect> {
+        Rect::from_coordinates(self.0)
+    }
+}
+
+impl Dimensions for LineSegment {
+    /// A `LineSegment` is 1-dimensional.
+    fn dimension(&self) -> u8 {
+        1
+    }
+}
+
+impl GeometryType for LineSegment {
+    /// A `LineSegment` is a `LineString` with exactly two `Point`s, so it shares its type name.
+    fn geometry_type(&self) -> &'static str {
+        "LineString"
+    }
+}
+
+impl EqualsExact for LineSegment {
+    fn equals_exact(&self, other: &Self, tolerance: f64) -> bool {
+        helpers::coordinates_equal(&self.0, &other.0, tolerance)
+    }
+}
+
+impl MapCoordinates for LineSegment {
+    fn map_coordinates<F: Fn([f64; 2]) -> [f64; 2]>(&self, function: F) -> Self {
+        LineSegment(self.0.map(function))
+    }
+}
+
+/// The storage behind a `LineString`'s coordinates.
+#[cfg(not(feature = "small-line-string"))]
+pub(crate) type LineStringCoordinates = Vec<[f64; 2]>;
+
+/// The storage behind a `LineString`'s coordinates. Up to 8 coordinates are stored inline,
+/// so short `LineString`s don't allocate on the heap.
+#[cfg(feature = "small-line-string")]
+pub(crate) type LineStringCoordinates = SmallVec<[[f64; 2]; 8]>;
+
+/// A sequence of two or more connected coordinates.
+///
+/// A `LineString` dereferences to a slice of its coordinates, `[[f64; 2]]`, whichever
+/// storage it uses, so enabling the `small-line-string` feature doesn't change the API.
+/// Before that feature was added it dereferenced to `Vec<[f64; 2]>`; callers relying on
+/// `Vec` methods should use `to_vec` instead.
+///
+/// With the `small-line-string` feature, up to 8 coordinates are stored inline, so
+/// short `LineString`s can be created and cloned without allocating.
+///
+/// # Examples:
+///
+/// ```
+/// use std::alloc::{GlobalAlloc, Layout, System};
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+///
+/// use auto_gis_with_rust::line_string::LineString;
+///
+/// struct Counting;
+///
+/// static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+///
+/// unsafe impl GlobalAlloc for Counting {
+///     unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+///         ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+///         System.alloc(layout)
+///     }
+///
+///     unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+///         System.dealloc(ptr, layout)
+///     }
+/// }
+///
+/// #[global_allocator]
+/// static GLOBAL: Counting = Counting;
+///
+/// let short = LineString::new(vec![[0., 0.], [1., 0.], [1., 1.]]).unwrap();
+/// let coordinates: &[[f64; 2]] = &short;
+///
+/// let before = ALLOCATIONS.load(Ordering::Relaxed);
+/// let copies: Vec<LineString> = (0..1000).map(|_| short.clone()).collect();
+/// let allocations = ALLOCATIONS.load(Ordering::Relaxed) - before;
+///
+/// assert_eq!(copies.len(), 1000);
+/// assert_eq!(coordinates.len(), 3);
+/// // One allocation holds the `Vec` of copies; without the feature, each copy adds one.
+/// if cfg!(feature = "small-line-string") {
+///     assert_eq!(allocations, 1);
+/// } else {
+///     assert_eq!(allocations, 1001);
+/// }
+/// ```
+#[derive(PartialEq, PartialOrd, Clone)]
+pub struct LineString(LineStringCoordinates);
 
 impl LineString {
     /// Construct a new `LineString` from a vector of 2-element arrays.
@@ -336,82 +611,1905 @@ impl LineString {
             Err(GeometryError::TooFewCoords(number_of_coordinates))
         } else {
             let float_coordinates = helpers::get_float_coordinates(coordinates);
-            Ok(LineString(float_coordinates))
+            Ok(LineString(float_coordinates.into_iter().collect()))
         }
     }
-}
-
-implement_deref!(LineString, Vec<[f64; 2]>);
 
-impl fmt::Display for LineString {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let points = self.iter().format_with(", ", |point, f| {
-            f(&format_args!("{} {}", point[0], point[1]))
-        });
-        write!(f, "LINESTRING ({})", points)
+    /// The boundary of this `LineString`: a `MultiPoint` of its start and end `Point`s,
+    /// or an empty `MultiPoint` if it is closed.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let open = LineString::new(vec![[0., 0.], [1., 0.], [1., 1.]]).unwrap();
+    /// let closed = LineString::new(vec![[0., 0.], [1., 0.], [1., 1.], [0., 0.]]).unwrap();
+    ///
+    /// assert_eq!(open.boundary().to_string(), "MULTIPOINT ((0 0), (1 1))");
+    /// assert_eq!(closed.boundary().to_string(), "MULTIPOINT ()");
+    /// ```
+    pub fn boundary(&self) -> geometry::Geometry {
+        let start = self[0];
+        let end = self[self.len() - 1];
+        let points = if start == end {
+            vec![]
+        } else {
+            vec![Point::from(start), Point::from(end)]
+        };
+        geometry::Geometry::MultiPoint(MultiPoint::new(points))
     }
-}
 
-#[derive(Debug, PartialEq, PartialOrd)]
-pub struct MultiLineString(Vec<LineString>);
+    /// The discrete Hausdorff distance between this `LineString` and another.
+    ///
+    /// This is the greatest distance from a vertex of either `LineString` to the nearest
+    /// vertex of the other. Only vertices are considered, so it can overestimate the true
+    /// Hausdorff distance between long segments; densify both lines first for a closer
+    /// approximation.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let line_string_1 = LineString::new(vec![[0., 0.], [1., 0.], [2., 0.]]).unwrap();
+    /// let line_string_2 = LineString::new(vec![[0., 3.], [1., 3.], [2., 3.]]).unwrap();
+    ///
+    /// assert_eq!(line_string_1.hausdorff_distance(&line_string_2), 3.);
+    /// ```
+    pub fn hausdorff_distance(&self, other: &LineString) -> f64 {
+        let directed = |from: &LineString, to: &LineString| {
+            from.iter()
+                .map(|vertex| {
+                    let vertex = Point::from(*vertex);
+                    to.iter()
+                        .map(|other_vertex| vertex.distance(&Point::from(*other_vertex)))
+                        .fold(f64::INFINITY, f64::min)
+                })
+                .fold(0., f64::max)
+        };
+        directed(self, other).max(directed(other, self))
+    }
 
-impl MultiLineString {
-    /// Construct a new `MultiLineString` from a vector of 'LineString's.
+    /// The discrete Fréchet distance between this `LineString` and another.
+    ///
+    /// Unlike the Hausdorff distance, this respects the order in which both lines are
+    /// traversed: it is the shortest leash that lets two walkers step forward from vertex
+    /// to vertex along each line, from start to end. Only vertices are considered, so
+    /// densify both lines first for a closer approximation of the continuous distance.
     ///
     /// # Examples:
     ///
     /// ```
-    /// use auto_gis_with_rust::line_string::{LineString, MultiLineString};
+    /// use auto_gis_with_rust::line_string::LineString;
     ///
-    /// let line_string_1 = LineString::new(vec![[0., 0.], [1., 0.], [1., 1.]]).unwrap();
-    /// let line_string_2 = LineString::new(vec![[1., 2.], [0., 2.], [0., 1.]]).unwrap();
+    /// let line_string_1 = LineString::new(vec![[0., 0.], [1., 0.], [2., 0.]]).unwrap();
+    /// let line_string_2 = LineString::new(vec![[0., 3.], [1., 3.], [2., 3.]]).unwrap();
+    /// let reversed = LineString::new(vec![[2., 0.], [1., 0.], [0., 0.]]).unwrap();
     ///
-    /// let multi_line_string = MultiLineString::new(vec![line_string_1, line_string_2]);
+    /// assert_eq!(line_string_1.frechet_distance(&line_string_1), 0.);
+    /// assert_eq!(line_string_1.frechet_distance(&line_string_2), 3.);
+    /// assert_eq!(line_string_1.hausdorff_distance(&reversed), 0.);
+    /// assert_eq!(line_string_1.frechet_distance(&reversed), 2.);
+    /// ```
+    pub fn frechet_distance(&self, other: &LineString) -> f64 {
+        let mut previous_row: Vec<f64> = Vec::with_capacity(other.len());
+        for (index, vertex) in self.iter().enumerate() {
+            let vertex = Point::from(*vertex);
+            let mut row: Vec<f64> = Vec::with_capacity(other.len());
+            for (other_index, other_vertex) in other.iter().enumerate() {
+                let distance = vertex.distance(&Point::from(*other_vertex));
+                let reachable = match (index, other_index) {
+                    (0, 0) => 0.,
+                    (0, _) => row[other_index - 1],
+                    (_, 0) => previous_row[0],
+                    _ => previous_row[other_index - 1]
+                        .min(previous_row[other_index])
+                        .min(row[other_index - 1]),
+                };
+                row.push(reachable.max(distance));
+            }
+            previous_row = row;
+        }
+        previous_row[other.len() - 1]
+    }
+
+    /// Simplify this `LineString` with the Douglas-Peucker algorithm.
+    ///
+    /// The interior vertex furthest from the line joining the start and end `Point`s is
+    /// kept if it lies further than `tolerance` from that line, and each half on either
+    /// side of it is simplified in turn. The start and end `Point`s are always kept. The
+    /// result may cross itself even where this `LineString` does not; see
+    /// [`simplify_preserve_topology`](LineString::simplify_preserve_topology).
+    ///
+    /// # Examples:
     ///
-    /// assert_eq!("MULTILINESTRING ((0 0, 1 0, 1 1), (1 2, 0 2, 0 1))", multi_line_string.to_string());
     /// ```
-    pub fn new(linestrings: Vec<LineString>) -> Self {
-        MultiLineString(linestrings)
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let line_string = LineString::new(vec![
+    ///     [0., 0.], [1., 0.1], [2., 0.], [3., 1.], [4., 0.], [5., 3.], [6., 0.],
+    /// ]).unwrap();
+    ///
+    /// assert_eq!(line_string.simplify(0.).len(), 7);
+    /// assert_eq!(line_string.simplify(0.5).to_string(), "LINESTRING (0 0, 2 0, 3 1, 4 0, 5 3, 6 0)");
+    /// assert_eq!(line_string.simplify(1.).to_string(), "LINESTRING (0 0, 4 0, 5 3, 6 0)");
+    /// assert_eq!(line_string.simplify(10.).to_string(), "LINESTRING (0 0, 6 0)");
+    /// ```
+    pub fn simplify(&self, tolerance: f64) -> LineString {
+        let last = self.len() - 1;
+        let mut keep = vec![false; self.len()];
+        keep[0] = true;
+        keep[last] = true;
+        douglas_peucker(self, 0, last, tolerance, &mut keep);
+        LineString(
+            self.iter()
+                .zip(keep)
+                .filter_map(|(coordinate, keep)| keep.then_some(*coordinate))
+                .collect(),
+        )
     }
-}
 
-implement_deref!(MultiLineString, Vec<LineString>);
+    /// Simplify this `LineString` with the Douglas-Peucker algorithm, without letting the
+    /// result cross or touch itself.
+    ///
+    /// The line is simplified as [`simplify`](LineString::simplify) does, but each run of
+    /// vertices is only removed if the chord replacing it would not cross or touch the rest
+    /// of the line as it stands at that moment. Otherwise the run is split at its farthest
+    /// vertex and each half is tried again, as if that vertex were beyond `tolerance`. If
+    /// this `LineString` is itself simple, so is the result. Consecutive repeated vertices
+    /// are removed, though at least two coordinates are always kept.
+    ///
+    /// # Examples:
+    ///
+    /// Dropping the dip at `(5, -0.3)` would let the line pass through the later dip at
+    /// `(5, -0.1)`, so it is kept.
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    /// use auto_gis_with_rust::traits::Geometry;
+    ///
+    /// let line_string = LineString::new(vec![
+    ///     [0., 0.], [5., -0.3], [10., 0.], [10., 4.], [5., -0.1], [0., 4.],
+    /// ]).unwrap();
+    /// let naive = line_string.simplify(0.5);
+    /// let preserved = line_string.simplify_preserve_topology(0.5);
+    ///
+    /// assert!(line_string.is_simple());
+    /// assert_eq!(naive.to_string(), "LINESTRING (0 0, 10 0, 10 4, 5 -0.1, 0 4)");
+    /// assert!(!naive.is_simple());
+    /// assert_eq!(preserved, line_string);
+    /// assert!(preserved.is_simple());
+    /// ```
+    ///
+    /// Removals that stay clear of the rest of the line still go ahead.
+    ///
+    /// ```
+    /// # use auto_gis_with_rust::line_string::LineString;
+    /// # use auto_gis_with_rust::traits::Geometry;
+    /// let line_string = LineString::new(vec![
+    ///     [0., 0.], [2., 0.1], [5., -0.3], [7., -0.1], [10., 0.], [10., 4.], [5., -0.1], [0., 4.],
+    /// ]).unwrap();
+    /// let preserved = line_string.simplify_preserve_topology(0.5);
+    ///
+    /// assert_eq!(preserved.to_string(), "LINESTRING (0 0, 5 -0.3, 10 0, 10 4, 5 -0.1, 0 4)");
+    /// assert!(preserved.is_simple());
+    /// ```
+    ///
+    /// A line through a single repeated `Point` keeps two coordinates.
+    ///
+    /// ```
+    /// # use auto_gis_with_rust::line_string::LineString;
+    /// let line_string = LineString::new(vec![[1, 1], [1, 1], [1, 1]]).unwrap();
+    ///
+    /// assert_eq!(line_string.simplify_preserve_topology(0.5).to_string(), "LINESTRING (1 1, 1 1)");
+    /// ```
+    pub fn simplify_preserve_topology(&self, tolerance: f64) -> LineString {
+        let mut vertices = self.to_vec();
+        vertices.dedup();
+        if vertices.len() < 2 {
+            return LineString(self[..2].into());
+        }
+        let mut keep = vec![true; vertices.len()];
+        douglas_peucker_preserve_topology(&vertices, 0, vertices.len() - 1, tolerance, &mut keep);
+        LineString(
+            vertices
+                .iter()
+                .zip(keep)
+                .filter_map(|(coordinate, keep)| keep.then_some(*coordinate))
+                .collect(),
+        )
+    }
 
-impl fmt::Display for MultiLineString {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let line_strings = self
-            .iter()
-            .map(|line_string| {
-                line_string.iter().format_with(", ", |point, f| {
-                    f(&format_args!("{} {}", point[0], point[1]))
+    /// Simplify this `LineString` with the Visvalingam–Whyatt algorithm.
+    ///
+    /// The interior vertex forming the smallest triangle with its two neighbours is removed
+    /// repeatedly, until every remaining triangle has an area of at least
+    /// `area_tolerance`. The start and end `Point`s are always kept.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let line_string = LineString::new(vec![
+    ///     [0., 0.], [1., 0.1], [2., 0.], [3., 1.], [4., 0.], [5., 3.], [6., 0.],
+    /// ]).unwrap();
+    ///
+    /// assert_eq!(line_string.simplify_vw(0.).len(), 7);
+    /// assert_eq!(line_string.simplify_vw(0.5).to_string(), "LINESTRING (0 0, 2 0, 3 1, 4 0, 5 3, 6 0)");
+    /// assert_eq!(line_string.simplify_vw(1.5).to_string(), "LINESTRING (0 0, 3 1, 4 0, 5 3, 6 0)");
+    /// assert_eq!(line_string.simplify_vw(2.5).to_string(), "LINESTRING (0 0, 4 0, 5 3, 6 0)");
+    /// assert_eq!(line_string.simplify_vw(100.).to_string(), "LINESTRING (0 0, 6 0)");
+    /// ```
+    pub fn simplify_vw(&self, area_tolerance: f64) -> LineString {
+        let mut coordinates = self.to_vec();
+        while coordinates.len() > 2 {
+            let (index, area) = (1..coordinates.len() - 1)
+                .map(|index| {
+                    let area = helpers::cross_product(
+                        coordinates[index - 1],
+                        coordinates[index],
+                        coordinates[index + 1],
+                    )
+                    .abs()
+                        / 2.;
+                    (index, area)
                 })
-            })
-            .format_with(", ", |line_string, f| f(&format_args!("({})", line_string)));
-        write!(f, "MULTILINESTRING ({})", line_strings)
+                .min_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap())
+                .unwrap();
+            if area >= area_tolerance {
+                break;
+            }
+            coordinates.remove(index);
+        }
+        LineString(coordinates.into_iter().collect())
     }
-}
 
-impl<T: NumCast> TryFrom<Vec<Vec<[T; 2]>>> for MultiLineString {
-    type Error = GeometryError;
+    /// Smooth this `LineString` by applying `iterations` rounds of Chaikin's corner cutting.
+    ///
+    /// Each round replaces every segment with two `Point`s at a quarter and three quarters
+    /// of its length, which roughly doubles the number of vertices. The start and end
+    /// `Point`s are kept in place.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let line_string = LineString::new(vec![[0., 0.], [4., 0.], [4., 4.]]).unwrap();
+    ///
+    /// assert_eq!(
+    ///     line_string.chaikin_smooth(2).to_string(),
+    ///     "LINESTRING (0 0, 2.25 0, 3.25 0.25, 3.75 0.75, 4 1.75, 4 4)",
+    /// );
+    /// ```
+    pub fn chaikin_smooth(&self, iterations: usize) -> LineString {
+        let mut coordinates = self.to_vec();
+        for _ in 0..iterations {
+            if coordinates.len() < 3 {
+                break;
+            }
+            let last = coordinates.len() - 2;
+            let mut smoothed = Vec::with_capacity(coordinates.len() * 2);
+            smoothed.push(coordinates[0]);
+            for (index, pair) in coordinates.windows(2).enumerate() {
+                let [[x_0, y_0], [x_1, y_1]] = [pair[0], pair[1]];
+                if index != 0 {
+                    smoothed.push([0.75 * x_0 + 0.25 * x_1, 0.75 * y_0 + 0.25 * y_1]);
+                }
+                if index != last {
+                    smoothed.push([0.25 * x_0 + 0.75 * x_1, 0.25 * y_0 + 0.75 * y_1]);
+                }
+            }
+            smoothed.push(coordinates[coordinates.len() - 1]);
+            coordinates = smoothed;
+        }
+        LineString(coordinates.into_iter().collect())
+    }
 
-    /// Tries to convert a vector or vectors of 2-float arrays into a `MultiLineString`.
+    /// Split this `LineString` where it passes closest to `point`, returning the two halves.
+    ///
+    /// The closest `Point` on the line ends the first half and starts the second, so no
+    /// part of the line is lost. Returns `None` if that `Point` is further than `tolerance`
+    /// from `point`, or if it is the start or end `Point`, where there is nothing to split.
     ///
     /// # Examples:
     ///
     /// ```
-    /// use std::convert::TryFrom;
-    /// use auto_gis_with_rust::line_string::{LineString, MultiLineString};
+    /// use auto_gis_with_rust::line_string::LineString;
+    /// use auto_gis_with_rust::point::Point;
     ///
-    /// let multi_line_string = MultiLineString::try_from(vec![
-    ///    vec![[0., 0.], [1., 0.], [1., 1.]],
-    ///    vec![[1., 2.], [0., 2.], [0., 1.]],
-    /// ]).unwrap();
+    /// let line_string = LineString::new(vec![[0., 0.], [2., 0.], [2., 2.]]).unwrap();
     ///
-    /// assert_eq!("MULTILINESTRING ((0 0, 1 0, 1 1), (1 2, 0 2, 0 1))", multi_line_string.to_string());
+    /// let (first, second) = line_string.split_at_point(&Point::new(1., 0.1), 0.5).unwrap();
+    /// assert_eq!(first.to_string(), "LINESTRING (0 0, 1 0)");
+    /// assert_eq!(second.to_string(), "LINESTRING (1 0, 2 0, 2 2)");
+    ///
+    /// assert!(line_string.split_at_point(&Point::new(1., 1.), 0.5).is_none());
     /// ```
-    fn try_from(vectors: Vec<Vec<[T; 2]>>) -> Result<Self, GeometryError> {
-        let line_strings: Result<Vec<LineString>, GeometryError> =
-            vectors.into_iter().map(LineString::new).collect();
-        Ok(MultiLineString::new(line_strings?))
+    pub fn split_at_point(
+        &self,
+        point: &Point,
+        tolerance: f64,
+    ) -> Option<(LineString, LineString)> {
+        let (index, split) = self
+            .windows(2)
+            .map(|pair| LineSegment::new([pair[0], pair[1]]).closest_point(point))
+            .enumerate()
+            .min_by(|(_, a), (_, b)| a.distance(point).partial_cmp(&b.distance(point)).unwrap())?;
+        let split = [split.x(), split.y()];
+        if Point::from(split).distance(point) > tolerance
+            || split == self[0]
+            || split == self[self.len() - 1]
+        {
+            return None;
+        }
+
+        let mut first = self[..=index].to_vec();
+        if first[first.len() - 1] != split {
+            first.push(split);
+        }
+        let mut second = vec![split];
+        second.extend(
+            self[index + 1..]
+                .iter()
+                .skip_while(|vertex| **vertex == split),
+        );
+        Some((
+            LineString(first.into_iter().collect()),
+            LineString(second.into_iter().collect()),
+        ))
+    }
+
+    /// The part of this `LineString` between `start_distance` and `end_distance`, measured
+    /// along the line from its start `Point`.
+    ///
+    /// Both distances are clamped to the length of the line, and new end `Point`s are
+    /// interpolated where they fall between vertices. If `start_distance` is greater than
+    /// `end_distance`, the two are swapped.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let line_string = LineString::new(vec![[0., 0.], [6., 0.], [9., 0.]]).unwrap();
+    ///
+    /// assert_eq!(line_string.substring(3., 6.).to_string(), "LINESTRING (3 0, 6 0)");
+    /// assert_eq!(line_string.substring(4., 20.).to_string(), "LINESTRING (4 0, 6 0, 9 0)");
+    /// assert_eq!(line_string.substring(6., 9.).to_string(), "LINESTRING (6 0, 9 0)");
+    /// assert_eq!(line_string.substring(6., 3.).to_string(), "LINESTRING (3 0, 6 0)");
+    /// ```
+    pub fn substring(&self, start_distance: f64, end_distance: f64) -> LineString {
+        let segments = self.segments();
+        let length: f64 = segments.iter().map(|segment| segment.length()).sum();
+        let (start_distance, end_distance) = (
+            start_distance.min(end_distance).clamp(0., length),
+            start_distance.max(end_distance).clamp(0., length),
+        );
+
+        let mut coordinates = Vec::new();
+        let mut travelled = 0.;
+        for segment in segments {
+            let segment_length = segment.length();
+            let interpolate = |distance: f64| {
+                let fraction = if segment_length == 0. {
+                    0.
+                } else {
+                    (distance - travelled) / segment_length
+                };
+                [
+                    segment[0][0] + fraction * segment.x_length(),
+                    segment[0][1] + fraction * segment.y_length(),
+                ]
+            };
+            let segment_end = travelled + segment_length;
+            if coordinates.is_empty() && start_distance <= segment_end {
+                coordinates.push(interpolate(start_distance));
+            }
+            if !coordinates.is_empty() {
+                if end_distance <= segment_end {
+                    coordinates.push(interpolate(end_distance));
+                    break;
+                }
+                if coordinates[coordinates.len() - 1] != segment[1] {
+                    coordinates.push(segment[1]);
+                }
+            }
+            travelled = segment_end;
+        }
+        LineString(coordinates.into_iter().collect())
+    }
+
+    /// A line parallel to this `LineString` at a perpendicular `distance`, to the left for
+    /// positive distances and to the right for negative ones.
+    ///
+    /// Every segment is shifted sideways and consecutive shifted segments are joined with
+    /// a miter, i.e. extended until they meet. Where the miter would reach further than
+    /// twice `distance` from the original vertex, as at sharp turns, the two segments are
+    /// joined with a bevel, i.e. a straight cut between their ends, instead. The ends of
+    /// a closed `LineString` are not joined to each other, and consecutive repeated
+    /// vertices are ignored.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let straight = LineString::new(vec![[0, 0], [10, 0]]).unwrap();
+    /// let corner = LineString::new(vec![[0, 0], [4, 0], [4, 4]]).unwrap();
+    ///
+    /// assert_eq!(straight.offset(2.).to_string(), "LINESTRING (0 2, 10 2)");
+    /// assert_eq!(straight.offset(-2.).to_string(), "LINESTRING (0 -2, 10 -2)");
+    /// assert_eq!(corner.offset(1.).to_string(), "LINESTRING (0 1, 3 1, 3 4)");
+    /// assert_eq!(corner.offset(-1.).to_string(), "LINESTRING (0 -1, 5 -1, 5 4)");
+    /// ```
+    pub fn offset(&self, distance: f64) -> LineString {
+        const MITER_LIMIT: f64 = 2.;
+
+        let mut vertices = self.to_vec();
+        vertices.dedup();
+        if vertices.len() < 2 || distance == 0. {
+            return self.clone();
+        }
+        let shifted: Vec<[[f64; 2]; 2]> = vertices
+            .windows(2)
+            .map(|pair| {
+                let [[x_0, y_0], [x_1, y_1]] = [pair[0], pair[1]];
+                let length = (x_1 - x_0).hypot(y_1 - y_0);
+                let [dx, dy] = [
+                    -(y_1 - y_0) / length * distance,
+                    (x_1 - x_0) / length * distance,
+                ];
+                [[x_0 + dx, y_0 + dy], [x_1 + dx, y_1 + dy]]
+            })
+            .collect();
+
+        let mut coordinates = vec![shifted[0][0]];
+        for (index, pair) in shifted.windows(2).enumerate() {
+            let [first, second] = [pair[0], pair[1]];
+            let first_direction = [first[1][0] - first[0][0], first[1][1] - first[0][1]];
+            let second_direction = [second[1][0] - second[0][0], second[1][1] - second[0][1]];
+            let denominator =
+                first_direction[0] * second_direction[1] - first_direction[1] * second_direction[0];
+            if denominator.abs() > 1e-12 * first_direction[0].hypot(first_direction[1]) {
+                let fraction = ((second[0][0] - first[0][0]) * second_direction[1]
+                    - (second[0][1] - first[0][1]) * second_direction[0])
+                    / denominator;
+                let miter = [
+                    first[0][0] + fraction * first_direction[0],
+                    first[0][1] + fraction * first_direction[1],
+                ];
+                let vertex = vertices[index + 1];
+                if (miter[0] - vertex[0]).hypot(miter[1] - vertex[1])
+                    <= MITER_LIMIT * distance.abs()
+                {
+                    coordinates.push(miter);
+                    continue;
+                }
+            }
+            coordinates.push(first[1]);
+            if second[0] != first[1] {
+                coordinates.push(second[0]);
+            }
+        }
+        coordinates.push(shifted[shifted.len() - 1][1]);
+        LineString(coordinates.into_iter().collect())
+    }
+
+    /// A `LineString` with vertices placed every `interval` along this `LineString`,
+    /// starting at its start `Point` and finishing at its end `Point`.
+    ///
+    /// Unlike the original vertices, which are discarded apart from the ends, the new
+    /// vertices are evenly spaced; only the last step may be shorter than `interval`.
+    /// A non-positive `interval` returns a clone.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let straight = LineString::new(vec![[0, 0], [10, 0]]).unwrap();
+    /// let corner = LineString::new(vec![[0, 0], [3, 0], [3, 4]]).unwrap();
+    ///
+    /// assert_eq!(straight.resample(2.).len(), 6);
+    /// assert_eq!(
+    ///     straight.resample(2.).to_string(),
+    ///     "LINESTRING (0 0, 2 0, 4 0, 6 0, 8 0, 10 0)"
+    /// );
+    /// assert_eq!(corner.resample(3.).to_string(), "LINESTRING (0 0, 3 0, 3 3, 3 4)");
+    /// ```
+    pub fn resample(&self, interval: f64) -> LineString {
+        if interval <= 0. || self.len() < 2 {
+            return self.clone();
+        }
+        let mut coordinates = vec![self[0]];
+        let mut next = interval;
+        let mut travelled = 0.;
+        for pair in self.windows(2) {
+            let segment = LineSegment::new([pair[0], pair[1]]);
+            let segment_length = segment.length();
+            while next < travelled + segment_length {
+                let fraction = (next - travelled) / segment_length;
+                coordinates.push([
+                    segment[0][0] + fraction * segment.x_length(),
+                    segment[0][1] + fraction * segment.y_length(),
+                ]);
+                next += interval;
+            }
+            travelled += segment_length;
+        }
+        let end = self[self.len() - 1];
+        let last = coordinates[coordinates.len() - 1];
+        if (end[0] - last[0]).hypot(end[1] - last[1]) <= 1e-9 * interval {
+            coordinates.pop();
+        }
+        coordinates.push(end);
+        LineString(coordinates.into_iter().collect())
+    }
+
+    /// Append `coordinate` to the end of this `LineString` in place.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let mut line_string = LineString::new(vec![[0, 0], [1, 0]]).unwrap();
+    /// line_string.push_point([1, 1]);
+    /// line_string.push_point([2.5, 1.]);
+    ///
+    /// assert_eq!(line_string.to_string(), "LINESTRING (0 0, 1 0, 1 1, 2.5 1)");
+    /// ```
+    pub fn push_point<T: NumCast>(&mut self, coordinate: [T; 2]) {
+        self.0
+            .push(coordinate.map(|coordinate| -> f64 { num_traits::cast(coordinate).unwrap() }));
+    }
+
+    /// The `LineSegment`s between each pair of consecutive vertices of this `LineString`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::{LineSegment, LineString};
+    ///
+    /// let line_string = LineString::new(vec![[0, 0], [1, 0], [1, 1]]).unwrap();
+    ///
+    /// assert_eq!(
+    ///     line_string.segments(),
+    ///     vec![LineSegment::new([[0, 0], [1, 0]]), LineSegment::new([[1, 0], [1, 1]])],
+    /// );
+    /// ```
+    pub fn segments(&self) -> Vec<LineSegment> {
+        self.windows(2)
+            .map(|pair| LineSegment::new([pair[0], pair[1]]))
+            .collect()
+    }
+
+    /// The angle in radians by which this `LineString` turns at each vertex other than its
+    /// start and end `Point`s.
+    ///
+    /// Angles are positive for left turns and negative for right turns, between `-π` and
+    /// `π`, and `0.0` where the `LineString` carries straight on. Take the absolute value
+    /// to find sharp turns in either direction.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use std::f64::consts::FRAC_PI_2;
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let l_shape = LineString::new(vec![[0, 0], [2, 0], [2, 2]]).unwrap();
+    /// let zigzag = LineString::new(vec![[0, 0], [1, 0], [2, 0], [2, -1]]).unwrap();
+    ///
+    /// assert_eq!(l_shape.vertex_angles(), vec![FRAC_PI_2]);
+    /// assert_eq!(zigzag.vertex_angles(), vec![0., -FRAC_PI_2]);
+    /// ```
+    pub fn vertex_angles(&self) -> Vec<f64> {
+        self.windows(3)
+            .map(|triple| {
+                let [[x_0, y_0], [x_1, y_1], [x_2, y_2]] = [triple[0], triple[1], triple[2]];
+                let [a, b] = [[x_1 - x_0, y_1 - y_0], [x_2 - x_1, y_2 - y_1]];
+                (a[0] * b[1] - a[1] * b[0]).atan2(a[0] * b[0] + a[1] * b[1])
+            })
+            .collect()
+    }
+
+    /// The midpoint of each segment of this `LineString`, in order.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// let line_string = LineString::new(vec![[0, 0], [2, 0], [2, 4]]).unwrap();
+    /// let midpoints: Vec<Point> = line_string.segment_midpoints().collect();
+    ///
+    /// assert_eq!(midpoints, vec![Point::new(1, 0), Point::new(2, 2)]);
+    /// ```
+    pub fn segment_midpoints(&self) -> impl Iterator<Item = Point> + '_ {
+        self.windows(2)
+            .map(|pair| LineSegment::new([pair[0], pair[1]]).centroid())
+    }
+
+    /// Split this `LineString`, with longitudes in degrees as its x coordinates, into
+    /// pieces that do not cross the antimeridian.
+    ///
+    /// A segment whose longitudes differ by more than 180° is taken to cross the
+    /// antimeridian, as the shorter way round the globe does. The `LineString` is cut
+    /// there, ending one piece at longitude `180` or `-180` and starting the next piece on
+    /// the other side, at a latitude interpolated linearly along the segment. Longitudes
+    /// are expected in the range `-180` to `180`; see
+    /// [`wrap_longitudes`](MapCoordinates::wrap_longitudes).
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let line_string = LineString::new(vec![[170, 0], [-170, 10]]).unwrap();
+    /// let not_crossing = LineString::new(vec![[-10, 0], [10, 10]]).unwrap();
+    ///
+    /// assert_eq!(
+    ///     line_string.split_at_antimeridian().to_string(),
+    ///     "MULTILINESTRING ((170 0, 180 5), (-180 5, -170 10))",
+    /// );
+    /// assert_eq!(not_crossing.split_at_antimeridian().len(), 1);
+    /// ```
+    pub fn split_at_antimeridian(&self) -> MultiLineString {
+        let mut pieces = Vec::new();
+        let mut piece = vec![self[0]];
+        for pair in self.windows(2) {
+            let [[x_0, y_0], [x_1, y_1]] = [pair[0], pair[1]];
+            if (x_1 - x_0).abs() > 180. {
+                let side = x_0.signum();
+                let fraction = (180. * side - x_0) / (x_1 + 360. * side - x_0);
+                let y = y_0 + fraction * (y_1 - y_0);
+                piece.push([180. * side, y]);
+                pieces.push(LineString(piece.into_iter().collect()));
+                piece = vec![[-180. * side, y]];
+            }
+            piece.push([x_1, y_1]);
+        }
+        pieces.push(LineString(piece.into_iter().collect()));
+        MultiLineString::new(pieces)
+    }
+
+    /// A copy of this `LineString` with vertices added where it bends, until every segment
+    /// lies within `max_offset` of a smooth curve through the original vertices.
+    ///
+    /// The smooth curve is the Catmull-Rom spline through the vertices, which follows
+    /// straight runs exactly and rounds off corners. Each segment is halved along the
+    /// spline, and halved again, for as long as the spline's midpoint lies further than
+    /// `max_offset` from the chord joining the ends of the part being halved. Vertices
+    /// therefore gather at sharp turns while straight stretches are left alone. The
+    /// original vertices are all kept, and a non-positive `max_offset` returns a clone.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let straight = LineString::new(vec![[0, 0], [5, 0], [10, 0]]).unwrap();
+    /// let gentle = LineString::new(vec![[0., 0.], [5., 0.01], [10., 0.]]).unwrap();
+    /// let corner = LineString::new(vec![[0, 0], [10, 0], [10, 10]]).unwrap();
+    ///
+    /// assert_eq!(straight.densify_to_tolerance(0.1), straight);
+    /// assert_eq!(gentle.densify_to_tolerance(0.1), gentle);
+    /// assert!(corner.densify_to_tolerance(0.1).len() > 6);
+    /// assert!(corner.densify_to_tolerance(0.01).len() > corner.densify_to_tolerance(0.1).len());
+    /// ```
+    pub fn densify_to_tolerance(&self, max_offset: f64) -> LineString {
+        const MAX_DEPTH: u32 = 16;
+
+        fn halve(
+            spline: &dyn Fn(f64) -> [f64; 2],
+            interval: [f64; 2],
+            ends: [[f64; 2]; 2],
+            max_offset: f64,
+            depth: u32,
+            coordinates: &mut Vec<[f64; 2]>,
+        ) {
+            let middle = (interval[0] + interval[1]) / 2.;
+            let point = spline(middle);
+            let offset = LineSegment::new(ends).distance(&Point::from(point));
+            if offset <= max_offset || depth == MAX_DEPTH {
+                return;
+            }
+            let next = [interval[0], middle];
+            halve(
+                spline,
+                next,
+                [ends[0], point],
+                max_offset,
+                depth + 1,
+                coordinates,
+            );
+            coordinates.push(point);
+            let next = [middle, interval[1]];
+            halve(
+                spline,
+                next,
+                [point, ends[1]],
+                max_offset,
+                depth + 1,
+                coordinates,
+            );
+        }
+
+        if max_offset <= 0. || self.len() < 2 {
+            return self.clone();
+        }
+        let last = self.len() - 1;
+        let mut coordinates = vec![self[0]];
+        for index in 0..last {
+            let [p_0, p_1, p_2, p_3] = [
+                self[index.saturating_sub(1)],
+                self[index],
+                self[index + 1],
+                self[(index + 2).min(last)],
+            ];
+            let spline = |t: f64| {
+                let [t_2, t_3] = [t * t, t * t * t];
+                [0, 1].map(|axis| {
+                    0.5 * (2. * p_1[axis]
+                        + (p_2[axis] - p_0[axis]) * t
+                        + (2. * p_0[axis] - 5. * p_1[axis] + 4. * p_2[axis] - p_3[axis]) * t_2
+                        + (3. * p_1[axis] - p_0[axis] - 3. * p_2[axis] + p_3[axis]) * t_3)
+                })
+            };
+            halve(
+                &spline,
+                [0., 1.],
+                [p_1, p_2],
+                max_offset,
+                0,
+                &mut coordinates,
+            );
+            coordinates.push(p_2);
+        }
+        LineString(coordinates.into_iter().collect())
+    }
+
+    /// The coordinates of this `LineString` packed into a single vector, as
+    /// `[x0, y0, x1, y1, ...]`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let line_string = LineString::new(vec![[0, 1], [2, 3]]).unwrap();
+    ///
+    /// assert_eq!(line_string.coords_flat(), vec![0., 1., 2., 3.]);
+    /// assert_eq!(LineString::from_coords_flat(&line_string.coords_flat()).unwrap(), line_string);
+    /// ```
+    pub fn coords_flat(&self) -> Vec<f64> {
+        self.iter().flatten().copied().collect()
+    }
+
+    /// Construct a new `LineString` from coordinates packed as `[x0, y0, x1, y1, ...]`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let line_string = LineString::from_coords_flat(&[0., 1., 2., 3.]).unwrap();
+    ///
+    /// assert_eq!(line_string.to_string(), "LINESTRING (0 1, 2 3)");
+    /// assert!(LineString::from_coords_flat(&[0., 1., 2.]).is_err());
+    /// assert!(LineString::from_coords_flat(&[0., 1.]).is_err());
+    /// ```
+    pub fn from_coords_flat(coordinates: &[f64]) -> Result<Self, GeometryError> {
+        if !coordinates.len().is_multiple_of(2) {
+            return Err(GeometryError::OddCoordinateCount(coordinates.len()));
+        }
+        LineString::new(
+            coordinates
+                .chunks_exact(2)
+                .map(|pair| [pair[0], pair[1]])
+                .collect(),
+        )
+    }
+
+    /// Returns true if this `LineString` runs in roughly the same direction as `reference`.
+    ///
+    /// The bearing from the start to the end of each `LineString` is found with
+    /// [`Point::bearing`], and the two must differ by at most `tolerance_deg` degrees.
+    /// Returns false if either `LineString` has fewer than two `Point`s.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let line_string = LineString::new(vec![[0, 0], [1, 1], [2, 1]]).unwrap();
+    /// let parallel = LineString::new(vec![[0, 1], [2, 2]]).unwrap();
+    /// let reversed = LineString::new(vec![[2, 1], [1, 1], [0, 0]]).unwrap();
+    ///
+    /// assert!(line_string.is_forward_of(&parallel, 5.));
+    /// assert!(!line_string.is_forward_of(&reversed, 5.));
+    /// ```
+    pub fn is_forward_of(&self, reference: &LineString, tolerance_deg: f64) -> bool {
+        let bearing = |line_string: &LineString| match (line_string.first(), line_string.last()) {
+            (Some(start), Some(end)) if line_string.len() > 1 => {
+                Some(Point::from(*start).bearing(&Point::from(*end)))
+            }
+            _ => None,
+        };
+        match (bearing(self), bearing(reference)) {
+            (Some(bearing), Some(reference_bearing)) => {
+                let difference = (bearing - reference_bearing).rem_euclid(360.);
+                difference.min(360. - difference) <= tolerance_deg
+            }
+            _ => false,
+        }
+    }
+
+    /// The `Point` a `distance` along this `LineString` from its start `Point`.
+    ///
+    /// `distance` is clamped to the length of the line, so negative distances give the
+    /// start `Point` and distances past the end give the end `Point`. Each call walks the
+    /// segments from the start; use [`MeasuredLineString`] to interpolate along the same
+    /// line many times.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// let line_string = LineString::new(vec![[0, 0], [4, 0], [4, 3]]).unwrap();
+    ///
+    /// assert_eq!(line_string.interpolate(2.), Point::new(2, 0));
+    /// assert_eq!(line_string.interpolate(5.), Point::new(4, 1));
+    /// assert_eq!(line_string.interpolate(-1.), Point::new(0, 0));
+    /// assert_eq!(line_string.interpolate(10.), Point::new(4, 3));
+    /// ```
+    pub fn interpolate(&self, distance: f64) -> Point {
+        let mut travelled = 0.;
+        for segment in self.segments() {
+            let segment_end = travelled + segment.length();
+            if distance < segment_end {
+                let fraction = ((distance - travelled) / (segment_end - travelled)).max(0.);
+                return Point::new(
+                    segment[0][0] + fraction * segment.x_length(),
+                    segment[0][1] + fraction * segment.y_length(),
+                );
+            }
+            travelled = segment_end;
+        }
+        Point::from(self[self.len() - 1])
+    }
+
+    /// A copy of this `LineString` with vertices added along every segment longer than
+    /// `max_segment_length`, splitting it into equal parts no longer than that.
+    ///
+    /// The new vertices lie on the original segments, so the shape is unchanged. A
+    /// non-positive `max_segment_length` returns a clone.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let line_string = LineString::new(vec![[0, 0], [3, 0], [3, 1]]).unwrap();
+    ///
+    /// assert_eq!(
+    ///     line_string.densify(1.2).to_string(),
+    ///     "LINESTRING (0 0, 1 0, 2 0, 3 0, 3 1)",
+    /// );
+    /// ```
+    pub fn densify(&self, max_segment_length: f64) -> LineString {
+        LineString(
+            densify_coordinates(self, max_segment_length)
+                .into_iter()
+                .collect(),
+        )
+    }
+
+    /// The `Point` on this `LineString` closest to `point`.
+    ///
+    /// Each segment is checked with [`LineSegment::closest_point`], and the first of
+    /// several equally close `Point`s is returned.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// let l_shape = LineString::new(vec![[0, 0], [4, 0], [4, 4]]).unwrap();
+    ///
+    /// assert_eq!(l_shape.closest_point(&Point::new(1, -1)), Point::new(1, 0));
+    /// assert_eq!(l_shape.closest_point(&Point::new(5, 3)), Point::new(4, 3));
+    /// assert_eq!(l_shape.closest_point(&Point::new(2, 3)), Point::new(4, 3));
+    /// ```
+    pub fn closest_point(&self, point: &Point) -> Point {
+        self.segments()
+            .iter()
+            .map(|segment| segment.closest_point(point))
+            .min_by(|a, b| a.distance(point).total_cmp(&b.distance(point)))
+            .unwrap_or_else(|| Point::from(self[0]))
+    }
+
+    /// The shortest distance between `point` and this `LineString`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// let l_shape = LineString::new(vec![[0, 0], [4, 0], [4, 4]]).unwrap();
+    ///
+    /// assert_eq!(l_shape.distance(&Point::new(2, 3)), 2.);
+    /// assert_eq!(l_shape.distance(&Point::new(7, 8)), 5.);
+    /// ```
+    pub fn distance(&self, point: &Point) -> f64 {
+        self.segments()
+            .iter()
+            .map(|segment| segment.distance(point))
+            .fold(f64::INFINITY, f64::min)
+    }
+
+    /// Returns true if the coordinates of this `LineString` no longer fit inline and have
+    /// been moved to the heap. Only available with the `small-line-string` feature.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let short = LineString::new(vec![[0., 0.], [1., 0.], [1., 1.]]).unwrap();
+    /// let long = LineString::new((0..9).map(|x| [x as f64, 0.]).collect::<Vec<_>>()).unwrap();
+    ///
+    /// assert!(!short.spilled());
+    /// assert!(long.spilled());
+    /// ```
+    #[cfg(feature = "small-line-string")]
+    pub fn spilled(&self) -> bool {
+        self.0.spilled()
+    }
+}
+
+implement_deref!(LineString, [[f64; 2]]);
+
+impl fmt::Display for LineString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let points = self.iter().format_with(", ", |point, f| {
+            f(&format_args!("{} {}", point[0], point[1]))
+        });
+        write!(f, "LINESTRING ({})", points)
+    }
+}
+
+impl fmt::Debug for LineString {
+    /// Summarize this `LineString` as its number of `Point`s and its envelope, or list
+    /// every coordinate in the alternate `{:#?}` form.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let line_string = LineString::new(vec![[0, 0], [2, 1], [1, 3]]).unwrap();
+    ///
+    /// assert_eq!(
+    ///     format!("{:?}", line_string),
+    ///     "LineString { points: 3, bbox: [[0.0, 0.0], [2.0, 3.0]] }",
+    /// );
+    /// assert!(format!("{:#?}", line_string).contains("2.0,"));
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        helpers::debug_geometry(
+            f,
+            "LineString",
+            &[("points", self.len())],
+            self.envelope(),
+            &self.0,
+        )
+    }
+}
+
+impl From<LineSegment> for LineString {
+    /// Construct a two-point `LineString` from a `LineSegment`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::{LineSegment, LineString, MultiLineString};
+    ///
+    /// let line_segment = LineSegment::new([[0, 0], [1, 2]]);
+    /// let line_string = LineString::from(line_segment.clone());
+    ///
+    /// assert_eq!(line_string.to_string(), line_segment.to_string());
+    /// assert_eq!(
+    ///     MultiLineString::new(vec![line_string]).to_string(),
+    ///     "MULTILINESTRING ((0 0, 1 2))",
+    /// );
+    /// ```
+    fn from(line_segment: LineSegment) -> Self {
+        LineString(line_segment.0.into_iter().collect())
+    }
+}
+
+impl Geometry for LineString {
+    /// Compute the geometric center of a geometry.
+    ///
+    /// For a `LineString`, this is the mean of its segments' midpoints weighted by their
+    /// lengths. A `LineString` with no length falls back to the mean of its vertices.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    /// use auto_gis_with_rust::point::Point;
+    /// use auto_gis_with_rust::traits::Geometry;
+    ///
+    /// let line_string = LineString::new(vec![[0, 0], [3, 0], [3, 1]]).unwrap();
+    ///
+    /// assert_eq!(line_string.centroid(), Point::new(1.875, 0.125));
+    /// ```
+    fn centroid(&self) -> Point {
+        let (length, [sum_x, sum_y]) =
+            self.windows(2)
+                .fold((0., [0., 0.]), |(length, [x, y]), pair| {
+                    let segment = LineSegment::new([pair[0], pair[1]]);
+                    let weight = segment.length();
+                    (
+                        length + weight,
+                        [
+                            x + weight * (pair[0][0] + pair[1][0]) / 2.,
+                            y + weight * (pair[0][1] + pair[1][1]) / 2.,
+                        ],
+                    )
+                });
+        if length != 0. {
+            return Point::new(sum_x / length, sum_y / length);
+        }
+        let count = self.len() as f64;
+        let [sum_x, sum_y] = self
+            .iter()
+            .fold([0., 0.], |[x, y], vertex| [x + vertex[0], y + vertex[1]]);
+        Point::new(sum_x / count, sum_y / count)
+    }
+
+    /// A `LineString` is simple if it doesn't pass through the same `Point` twice,
+    /// except that a closed `LineString` meets itself at its start and end `Point`.
+    ///
+    /// That coincidence of the first and last vertices is the only one allowed: a closed
+    /// `LineString` that returns to one of its other vertices, or to its start `Point`
+    /// part-way round, is not simple. Consecutive repeated vertices are ignored.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    /// use auto_gis_with_rust::traits::Geometry;
+    ///
+    /// let simple = LineString::new(vec![[0, 0], [2, 0], [2, 2], [0, 0]]).unwrap();
+    /// let crossing = LineString::new(vec![[0, 0], [2, 2], [2, 0], [0, 2]]).unwrap();
+    ///
+    /// assert!(simple.is_simple());
+    /// assert!(!crossing.is_simple());
+    /// ```
+    ///
+    /// A closed `LineString` touching itself at an interior vertex is not simple, and
+    /// neither is one passing back through its start `Point`.
+    ///
+    /// ```
+    /// # use auto_gis_with_rust::line_string::LineString;
+    /// # use auto_gis_with_rust::traits::Geometry;
+    /// let clean = LineString::new(vec![[0, 0], [2, 0], [2, 2], [0, 2], [0, 0]]).unwrap();
+    /// let figure_eight = LineString::new(vec![
+    ///     [0, 0], [2, 0], [1, 1], [2, 2], [0, 2], [1, 1], [0, 0],
+    /// ]).unwrap();
+    /// let two_loops = LineString::new(vec![
+    ///     [0, 0], [1, 1], [1, 2], [0, 0], [-1, 2], [-1, 1], [0, 0],
+    /// ]).unwrap();
+    ///
+    /// assert!(clean.is_simple());
+    /// assert!(!figure_eight.is_simple());
+    /// assert!(!two_loops.is_simple());
+    /// ```
+    fn is_simple(&self) -> bool {
+        let mut vertices = self.to_vec();
+        vertices.dedup();
+        self_intersections(&vertices).is_empty()
+    }
+}
+
+/// The pairs of indices of segments of the line through `vertices` that meet anywhere
+/// other than at a vertex they share as neighbours, or at the start and end of a closed
+/// line, each with the first coordinate where they do.
+pub(crate) fn self_intersections(vertices: &[[f64; 2]]) -> Vec<(usize, usize, [f64; 2])> {
+    let segments: Vec<LineSegment> = vertices
+        .windows(2)
+        .map(|pair| LineSegment::new([pair[0], pair[1]]))
+        .collect();
+    let closed = segments.len() > 1 && vertices[0] == vertices[vertices.len() - 1];
+    let mut pairs = Vec::new();
+    for (index, segment) in segments.iter().enumerate() {
+        for (other_index, other) in segments.iter().enumerate().skip(index + 1) {
+            let shared = if other_index == index + 1 {
+                Some(segment[1])
+            } else if closed && index == 0 && other_index == segments.len() - 1 {
+                Some(segment[0])
+            } else {
+                None
+            };
+            let intersections = segment.intersection_coordinates(other);
+            if let Some(coordinate) = intersections
+                .into_iter()
+                .find(|coordinate| Some(*coordinate) != shared)
+            {
+                pairs.push((index, other_index, coordinate));
+            }
+        }
+    }
+    pairs
+}
+
+/// The interior vertex between `start` and `end` furthest from the chord joining them,
+/// with its distance from the chord, or `None` if they are neighbours.
+fn farthest_vertex(vertices: &[[f64; 2]], start: usize, end: usize) -> Option<(usize, f64)> {
+    let chord = LineSegment::new([vertices[start], vertices[end]]);
+    (start + 1..end)
+        .map(|index| (index, chord.distance(&Point::from(vertices[index]))))
+        .fold(None, |farthest, (index, distance)| match farthest {
+            Some((_, farthest_distance)) if farthest_distance >= distance => farthest,
+            _ => Some((index, distance)),
+        })
+}
+
+/// Mark the vertices between `start` and `end` that the Douglas-Peucker algorithm keeps
+/// at `tolerance`.
+fn douglas_peucker(
+    vertices: &[[f64; 2]],
+    start: usize,
+    end: usize,
+    tolerance: f64,
+    keep: &mut [bool],
+) {
+    if let Some((index, distance)) = farthest_vertex(vertices, start, end) {
+        if distance > tolerance {
+            keep[index] = true;
+            douglas_peucker(vertices, start, index, tolerance, keep);
+            douglas_peucker(vertices, index, end, tolerance, keep);
+        }
+    }
+}
+
+/// Remove the vertices between `start` and `end` that the Douglas-Peucker algorithm drops
+/// at `tolerance`, as marked in `keep`, skipping any removal whose chord would cross or
+/// touch the line formed by the vertices still kept.
+fn douglas_peucker_preserve_topology(
+    vertices: &[[f64; 2]],
+    start: usize,
+    end: usize,
+    tolerance: f64,
+    keep: &mut [bool],
+) {
+    let Some((index, distance)) = farthest_vertex(vertices, start, end) else {
+        return;
+    };
+    if distance <= tolerance
+        && vertices[start] != vertices[end]
+        && !chord_meets_line(vertices, keep, start, end)
+    {
+        keep[start + 1..end].fill(false);
+        return;
+    }
+    douglas_peucker_preserve_topology(vertices, start, index, tolerance, keep);
+    douglas_peucker_preserve_topology(vertices, index, end, tolerance, keep);
+}
+
+/// Returns true if the chord from `start` to `end` would cross, overlap or touch a segment
+/// of the line through the vertices marked in `keep` outside that span, anywhere other than
+/// at a single end it shares with its neighbour.
+fn chord_meets_line(vertices: &[[f64; 2]], keep: &[bool], start: usize, end: usize) -> bool {
+    let last = vertices.len() - 1;
+    let closed = vertices[0] == vertices[last];
+    let chord = LineSegment::new([vertices[start], vertices[end]]);
+    let kept: Vec<usize> = (0..vertices.len())
+        .filter(|index| keep[*index] && (*index <= start || *index >= end))
+        .collect();
+    kept.windows(2).any(|pair| {
+        let (a, b) = (pair[0], pair[1]);
+        if (a, b) == (start, end) {
+            return false;
+        }
+        let shared: Vec<[f64; 2]> = [
+            (b == start, start),
+            (a == end, end),
+            (closed && start == 0 && b == last, start),
+            (closed && end == last && a == 0, end),
+        ]
+        .into_iter()
+        .filter_map(|(is_shared, index)| is_shared.then_some(vertices[index]))
+        .collect();
+        let meeting = chord.intersection_coordinates(&LineSegment::new([vertices[a], vertices[b]]));
+        meeting.len() > 1
+            || meeting
+                .iter()
+                .any(|coordinate| !shared.contains(coordinate))
+    })
+}
+
+impl Curve for LineString {
+    /// The length of this `LineString` in its associated spatial reference.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    /// use auto_gis_with_rust::traits::Curve;
+    ///
+    /// let line_string = LineString::new(vec![[0, 0], [3, 4], [3, 6]]).unwrap();
+    ///
+    /// assert_eq!(line_string.length(), 7.);
+    /// ```
+    fn length(&self) -> f64 {
+        self.windows(2)
+            .map(|pair| LineSegment::new([pair[0], pair[1]]).length())
+            .sum()
+    }
+
+    /// The first `Point` of this `LineString`.
+    fn start_point(&self) -> Point {
+        Point::from(self[0])
+    }
+
+    /// The last `Point` of this `LineString`.
+    fn end_point(&self) -> Point {
+        Point::from(self[self.len() - 1])
+    }
+
+    /// Returns true if the start `Point` of this `LineString` is equal to its end `Point`.
+    fn is_closed(&self) -> bool {
+        self.start_point() == self.end_point()
+    }
+
+    /// Returns true if this `LineString` is both closed and simple.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    /// use auto_gis_with_rust::traits::Curve;
+    ///
+    /// let ring = LineString::new(vec![[0, 0], [1, 0], [1, 1], [0, 0]]).unwrap();
+    /// let open = LineString::new(vec![[0, 0], [1, 0], [1, 1]]).unwrap();
+    ///
+    /// assert!(ring.is_ring());
+    /// assert!(!open.is_ring());
+    /// ```
+    fn is_ring(&self) -> bool {
+        self.is_closed() && self.is_simple()
+    }
+}
+
+impl ConvexHull for LineString {
+    /// The convex hull of the vertices of this `LineString`.
+    fn convex_hull(&self) -> Result<Polygon, GeometryError> {
+        helpers::convex_hull_polygon(self)
+    }
+}
+
+impl Envelope for LineString {
+    /// The smallest `Rect` containing every vertex of this `LineString`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    /// use auto_gis_with_rust::rect::Rect;
+    /// use auto_gis_with_rust::traits::Envelope;
+    ///
+    /// let line_string = LineString::new(vec![[0, 0], [3, -1], [1, 2]]).unwrap();
+    ///
+    /// assert_eq!(line_string.envelope(), Some(Rect::new([0, -1], [3, 2])));
+    /// ```
+    fn envelope(&self) -> Option<Rect> {
+        Rect::from_coordinates(self.iter().copied())
+    }
+}
+
+impl Dimensions for LineString {
+    /// A `LineString` is 1-dimensional.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    /// use auto_gis_with_rust::traits::Dimensions;
+    ///
+    /// let line_string = LineString::new(vec![[0., 0.], [1., 0.], [1., 1.]]).unwrap();
+    ///
+    /// assert_eq!(line_string.dimension(), 1);
+    /// ```
+    fn dimension(&self) -> u8 {
+        1
+    }
+}
+
+impl GeometryType for LineString {
+    fn geometry_type(&self) -> &'static str {
+        "LineString"
+    }
+}
+
+impl EqualsExact for LineString {
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    /// use auto_gis_with_rust::traits::EqualsExact;
+    ///
+    /// let line_string_1 = LineString::new(vec![[0., 0.], [1., 0.]]).unwrap();
+    /// let line_string_2 = LineString::new(vec![[0., 1e-9], [1., 0.]]).unwrap();
+    /// let line_string_3 = LineString::new(vec![[1., 0.], [0., 0.]]).unwrap();
+    ///
+    /// assert!(line_string_1.equals_exact(&line_string_2, 1e-6));
+    /// assert!(!line_string_1.equals_exact(&line_string_3, 1e-6));
+    /// ```
+    fn equals_exact(&self, other: &Self, tolerance: f64) -> bool {
+        helpers::coordinates_equal(self, other, tolerance)
+    }
+}
+
+impl MapCoordinates for LineString {
+    fn map_coordinates<F: Fn([f64; 2]) -> [f64; 2]>(&self, function: F) -> Self {
+        LineString(self.iter().copied().map(function).collect())
+    }
+
+    #[cfg(feature = "parallel")]
+    fn par_map_coordinates<F: Fn([f64; 2]) -> [f64; 2] + Sync + Send>(&self, function: F) -> Self {
+        let coordinates: Vec<[f64; 2]> = self.par_iter().copied().map(function).collect();
+        LineString(coordinates.into_iter().collect())
+    }
+}
+
+/// `coordinates` with vertices added along every segment longer than `max_segment_length`,
+/// splitting it into equal parts no longer than that.
+pub(crate) fn densify_coordinates(
+    coordinates: &[[f64; 2]],
+    max_segment_length: f64,
+) -> Vec<[f64; 2]> {
+    if max_segment_length <= 0. || coordinates.is_empty() {
+        return coordinates.to_vec();
+    }
+    let mut densified = vec![coordinates[0]];
+    for pair in coordinates.windows(2) {
+        let [[x_0, y_0], [x_1, y_1]] = [pair[0], pair[1]];
+        let parts = ((x_1 - x_0).hypot(y_1 - y_0) / max_segment_length)
+            .ceil()
+            .max(1.) as usize;
+        densified.extend((1..parts).map(|part| {
+            let fraction = part as f64 / parts as f64;
+            [x_0 + fraction * (x_1 - x_0), y_0 + fraction * (y_1 - y_0)]
+        }));
+        densified.push(pair[1]);
+    }
+    densified
+}
+
+/// A `LineString` together with the distance along it to each of its vertices, for fast
+/// repeated linear referencing.
+///
+/// The distances are computed once on construction, after which [`length`] takes constant
+/// time and [`interpolate`] finds its segment by binary search. A `MeasuredLineString`
+/// cannot be modified, and dereferences to the `LineString` it wraps.
+///
+/// [`length`]: MeasuredLineString::length
+/// [`interpolate`]: MeasuredLineString::interpolate
+///
+/// # Examples:
+///
+/// ```
+/// use auto_gis_with_rust::line_string::{LineString, MeasuredLineString};
+/// use auto_gis_with_rust::traits::Curve;
+///
+/// let line_string = LineString::new(vec![[0., 0.], [3., 4.], [3., 5.], [7., 2.], [7., 2.]]).unwrap();
+/// let measured = MeasuredLineString::from(line_string.clone());
+///
+/// assert_eq!(measured.length(), line_string.length());
+/// for step in -5..=60 {
+///     let distance = step as f64 * 0.25;
+///     assert_eq!(measured.interpolate(distance), line_string.interpolate(distance));
+/// }
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct MeasuredLineString(LineString, Vec<f64>);
+
+impl MeasuredLineString {
+    /// Construct a new `MeasuredLineString` from a vector of 2-element arrays, validating
+    /// them as [`LineString::new`] does.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::MeasuredLineString;
+    ///
+    /// let measured = MeasuredLineString::new(vec![[0, 0], [3, 4]]).unwrap();
+    ///
+    /// assert_eq!(measured.length(), 5.);
+    /// assert!(MeasuredLineString::new(vec![[0, 0]]).is_err());
+    /// ```
+    pub fn new<T: NumCast>(coordinates: Vec<[T; 2]>) -> Result<Self, GeometryError> {
+        LineString::new(coordinates).map(MeasuredLineString::from)
+    }
+
+    /// The length of the measured `LineString`, computed once on construction.
+    pub fn length(&self) -> f64 {
+        self.1[self.1.len() - 1]
+    }
+
+    /// The `Point` a `distance` along the measured `LineString` from its start `Point`,
+    /// exactly as [`LineString::interpolate`] finds it.
+    pub fn interpolate(&self, distance: f64) -> Point {
+        let index = self.1.partition_point(|travelled| *travelled <= distance);
+        if index == 0 {
+            return Point::from(self.0[0]);
+        }
+        if index == self.1.len() {
+            return Point::from(self.0[index - 1]);
+        }
+        let [[x_0, y_0], [x_1, y_1]] = [self.0[index - 1], self.0[index]];
+        let fraction = (distance - self.1[index - 1]) / (self.1[index] - self.1[index - 1]);
+        Point::new(x_0 + fraction * (x_1 - x_0), y_0 + fraction * (y_1 - y_0))
+    }
+
+    /// Consume this `MeasuredLineString`, returning the `LineString` it wraps.
+    pub fn into_inner(self) -> LineString {
+        self.0
+    }
+}
+
+implement_deref!(MeasuredLineString, LineString);
+
+impl From<LineString> for MeasuredLineString {
+    /// Measure `line_string`, computing the distance along it to each of its vertices.
+    fn from(line_string: LineString) -> Self {
+        let mut travelled = 0.;
+        let distances = std::iter::once(0.)
+            .chain(line_string.segments().iter().map(|segment| {
+                travelled += segment.length();
+                travelled
+            }))
+            .collect();
+        MeasuredLineString(line_string, distances)
+    }
+}
+
+#[derive(Default, PartialEq, PartialOrd, Clone)]
+pub struct MultiLineString(Vec<LineString>);
+
+impl MultiLineString {
+    /// Construct a new `MultiLineString` from a vector of 'LineString's.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::{LineString, MultiLineString};
+    ///
+    /// let line_string_1 = LineString::new(vec![[0., 0.], [1., 0.], [1., 1.]]).unwrap();
+    /// let line_string_2 = LineString::new(vec![[1., 2.], [0., 2.], [0., 1.]]).unwrap();
+    ///
+    /// let multi_line_string = MultiLineString::new(vec![line_string_1, line_string_2]);
+    ///
+    /// assert_eq!("MULTILINESTRING ((0 0, 1 0, 1 1), (1 2, 0 2, 0 1))", multi_line_string.to_string());
+    /// ```
+    pub fn new(linestrings: Vec<LineString>) -> Self {
+        MultiLineString(linestrings)
+    }
+
+    /// The boundary of this `MultiLineString`, following the "mod 2" rule: a `MultiPoint`
+    /// of the end `Point`s that occur in an odd number of its `LineString`s.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use auto_gis_with_rust::line_string::MultiLineString;
+    ///
+    /// let multi_line_string = MultiLineString::try_from(vec![
+    ///    vec![[0., 0.], [1., 0.]],
+    ///    vec![[1., 0.], [1., 1.]],
+    /// ]).unwrap();
+    ///
+    /// assert_eq!(multi_line_string.boundary().to_string(), "MULTIPOINT ((0 0), (1 1))");
+    /// ```
+    pub fn boundary(&self) -> geometry::Geometry {
+        let end_points: Vec<[f64; 2]> = self
+            .iter()
+            .filter(|line_string| line_string[0] != line_string[line_string.len() - 1])
+            .flat_map(|line_string| [line_string[0], line_string[line_string.len() - 1]])
+            .collect();
+        let points: Vec<Point> = end_points
+            .iter()
+            .enumerate()
+            .filter(|(index, point)| {
+                !end_points[..*index].contains(point)
+                    && end_points.iter().filter(|other| other == point).count() % 2 == 1
+            })
+            .map(|(_, point)| Point::from(*point))
+            .collect();
+        geometry::Geometry::MultiPoint(MultiPoint::new(points))
+    }
+
+    /// The mean of this `MultiLineString`'s `LineString` centroids weighted by their
+    /// lengths, or `None` if it is empty.
+    ///
+    /// If none of the `LineString`s have any length, this is the mean of all their
+    /// vertices.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::{LineString, MultiLineString};
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// let degenerate = LineString::new(vec![[1, 1], [1, 1]]).unwrap();
+    ///
+    /// assert_eq!(MultiLineString::new(vec![degenerate]).checked_centroid(), Some(Point::new(1, 1)));
+    /// assert_eq!(MultiLineString::new(vec![]).checked_centroid(), None);
+    /// ```
+    pub fn checked_centroid(&self) -> Option<Point> {
+        if self.is_empty() {
+            return None;
+        }
+        let total_length: f64 = self.iter().map(|line_string| line_string.length()).sum();
+        if total_length == 0. {
+            let count: usize = self.iter().map(|line_string| line_string.len()).sum();
+            let [sum_x, sum_y] = self
+                .iter()
+                .flat_map(|line_string| line_string.iter())
+                .fold([0., 0.], |[x, y], vertex| [x + vertex[0], y + vertex[1]]);
+            return Some(Point::new(sum_x / count as f64, sum_y / count as f64));
+        }
+        let [sum_x, sum_y] = self.iter().fold([0., 0.], |[x, y], line_string| {
+            let weight = line_string.length();
+            let centroid = line_string.centroid();
+            [x + weight * centroid.x(), y + weight * centroid.y()]
+        });
+        Some(Point::new(sum_x / total_length, sum_y / total_length))
+    }
+
+    /// The `LineString`s that make up this `MultiLineString`.
+    pub fn line_strings(&self) -> &[LineString] {
+        &self.0
+    }
+
+    /// Consume this `MultiLineString`, returning its owned `LineString`s.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::{LineString, MultiLineString};
+    ///
+    /// let first = LineString::new(vec![[0, 0], [1, 1]]).unwrap();
+    /// let second = LineString::new(vec![[2, 2], [3, 3]]).unwrap();
+    /// let multi_line_string = MultiLineString::new(vec![first.clone(), second.clone()]);
+    ///
+    /// assert_eq!(multi_line_string.line_strings().len(), 2);
+    /// assert_eq!(multi_line_string.into_line_strings(), vec![first, second]);
+    /// ```
+    pub fn into_line_strings(self) -> Vec<LineString> {
+        self.0
+    }
+
+    /// The total number of vertices in every `LineString` in this `MultiLineString`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use auto_gis_with_rust::line_string::MultiLineString;
+    ///
+    /// let multi_line_string =
+    ///     MultiLineString::try_from(vec![vec![[0, 0], [1, 0]], vec![[1, 1], [0, 1], [0, 2]]])
+    ///         .unwrap();
+    ///
+    /// assert_eq!(multi_line_string.num_points(), 5);
+    /// ```
+    pub fn num_points(&self) -> usize {
+        self.iter().map(|line_string| line_string.len()).sum()
+    }
+}
+
+implement_deref!(MultiLineString, Vec<LineString>);
+
+impl fmt::Display for MultiLineString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let line_strings = self
+            .iter()
+            .map(|line_string| {
+                line_string.iter().format_with(", ", |point, f| {
+                    f(&format_args!("{} {}", point[0], point[1]))
+                })
+            })
+            .format_with(", ", |line_string, f| f(&format_args!("({})", line_string)));
+        write!(f, "MULTILINESTRING ({})", line_strings)
+    }
+}
+
+impl fmt::Debug for MultiLineString {
+    /// Summarize this `MultiLineString` as its numbers of `LineString`s and `Point`s and
+    /// its envelope, or list every coordinate in the alternate `{:#?}` form.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use auto_gis_with_rust::line_string::MultiLineString;
+    ///
+    /// let multi_line_string = MultiLineString::try_from(vec![
+    ///     vec![[0, 0], [1, 1]],
+    ///     vec![[2, 2], [3, 2], [3, 3]],
+    /// ]).unwrap();
+    ///
+    /// assert_eq!(
+    ///     format!("{:?}", multi_line_string),
+    ///     "MultiLineString { line_strings: 2, points: 5, bbox: [[0.0, 0.0], [3.0, 3.0]] }",
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        helpers::debug_geometry(
+            f,
+            "MultiLineString",
+            &[
+                ("line_strings", self.len()),
+                (
+                    "points",
+                    self.iter().map(|line_string| line_string.len()).sum(),
+                ),
+            ],
+            self.envelope(),
+            &self.0,
+        )
+    }
+}
+
+impl<T: NumCast> TryFrom<Vec<Vec<[T; 2]>>> for MultiLineString {
+    type Error = GeometryError;
+
+    /// Tries to convert a vector or vectors of 2-float arrays into a `MultiLineString`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use auto_gis_with_rust::line_string::{LineString, MultiLineString};
+    ///
+    /// let multi_line_string = MultiLineString::try_from(vec![
+    ///    vec![[0., 0.], [1., 0.], [1., 1.]],
+    ///    vec![[1., 2.], [0., 2.], [0., 1.]],
+    /// ]).unwrap();
+    ///
+    /// assert_eq!("MULTILINESTRING ((0 0, 1 0, 1 1), (1 2, 0 2, 0 1))", multi_line_string.to_string());
+    /// ```
+    fn try_from(vectors: Vec<Vec<[T; 2]>>) -> Result<Self, GeometryError> {
+        let line_strings: Result<Vec<LineString>, GeometryError> =
+            vectors.into_iter().map(LineString::new).collect();
+        Ok(MultiLineString::new(line_strings?))
+    }
+}
+
+impl<I: SliceIndex<[LineString]>> Index<I> for MultiLineString {
+    type Output = I::Output;
+
+    /// The `LineString` at `index`, or the slice of `LineString`s in a range of indices, panicking if
+    /// `index` is out of bounds.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::{LineString, MultiLineString};
+    ///
+    /// let line_string = LineString::new(vec![[0, 0], [1, 1]]).unwrap();
+    /// let multi_line_string = MultiLineString::new(vec![line_string.clone()]);
+    ///
+    /// assert_eq!(multi_line_string[0], line_string);
+    /// ```
+    fn index(&self, index: I) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl Extend<LineString> for MultiLineString {
+    /// Append the `LineString`s yielded by `iter` to this `MultiLineString`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::{LineString, MultiLineString};
+    ///
+    /// let mut multi_line_string = MultiLineString::default();
+    /// multi_line_string.extend(vec![LineString::new(vec![[0, 0], [1, 1]]).unwrap()]);
+    ///
+    /// assert_eq!(multi_line_string.to_string(), "MULTILINESTRING ((0 0, 1 1))");
+    /// ```
+    fn extend<I: IntoIterator<Item = LineString>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
+impl IntoIterator for MultiLineString {
+    type Item = LineString;
+    type IntoIter = std::vec::IntoIter<LineString>;
+
+    /// Consume this `MultiLineString`, yielding its owned `LineString`s.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use auto_gis_with_rust::line_string::{LineString, MultiLineString};
+    ///
+    /// let multi_line_string = MultiLineString::try_from(vec![
+    ///    vec![[0., 0.], [1., 0.]],
+    ///    vec![[1., 1.], [0., 1.]],
+    /// ]).unwrap();
+    /// let line_strings: Vec<LineString> = multi_line_string.into_iter().collect();
+    ///
+    /// assert_eq!(line_strings[1].to_string(), "LINESTRING (1 1, 0 1)");
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a MultiLineString {
+    type Item = &'a LineString;
+    type IntoIter = std::slice::Iter<'a, LineString>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl GeometryType for MultiLineString {
+    fn geometry_type(&self) -> &'static str {
+        "MultiLineString"
+    }
+}
+
+impl EqualsExact for MultiLineString {
+    fn equals_exact(&self, other: &Self, tolerance: f64) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(line_string, other)| line_string.equals_exact(other, tolerance))
+    }
+}
+
+impl MapCoordinates for MultiLineString {
+    fn map_coordinates<F: Fn([f64; 2]) -> [f64; 2]>(&self, function: F) -> Self {
+        MultiLineString(
+            self.iter()
+                .map(|line_string| line_string.map_coordinates(&function))
+                .collect(),
+        )
+    }
+
+    #[cfg(feature = "parallel")]
+    fn par_map_coordinates<F: Fn([f64; 2]) -> [f64; 2] + Sync + Send>(&self, function: F) -> Self {
+        MultiLineString(
+            self.par_iter()
+                .map(|line_string| line_string.par_map_coordinates(&function))
+                .collect(),
+        )
+    }
+}
+
+impl Geometry for MultiLineString {
+    /// Compute the geometric center of a geometry.
+    ///
+    /// For a `MultiLineString`, this is the mean of its `LineString`s' centroids weighted
+    /// by their lengths. See [`MultiLineString::checked_centroid`] for a version that
+    /// doesn't panic.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this `MultiLineString` is empty.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::{LineString, MultiLineString};
+    /// use auto_gis_with_rust::point::Point;
+    /// use auto_gis_with_rust::traits::Geometry;
+    ///
+    /// let long = LineString::new(vec![[0, 0], [4, 0]]).unwrap();
+    /// let short = LineString::new(vec![[10, 0], [10, 1]]).unwrap();
+    /// let multi_line_string = MultiLineString::new(vec![long, short]);
+    ///
+    /// assert_eq!(multi_line_string.centroid(), Point::new(3.6, 0.1));
+    /// ```
+    fn centroid(&self) -> Point {
+        self.checked_centroid()
+            .expect("an empty MultiLineString has no centroid")
+    }
+
+    /// A `MultiLineString` is simple if all of its `LineString`s are simple and they only
+    /// meet each other at `Point`s on both of their boundaries.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::MultiLineString;
+    /// use auto_gis_with_rust::traits::Geometry;
+    /// use std::convert::TryFrom;
+    ///
+    /// let touching = MultiLineString::try_from(vec![vec![[0, 0], [1, 1]], vec![[1, 1], [2, 0]]]).unwrap();
+    /// let crossing = MultiLineString::try_from(vec![vec![[0, 0], [2, 2]], vec![[0, 2], [2, 0]]]).unwrap();
+    ///
+    /// assert!(touching.is_simple());
+    /// assert!(!crossing.is_simple());
+    /// ```
+    fn is_simple(&self) -> bool {
+        let end_points = |line_string: &LineString| {
+            if line_string.is_closed() {
+                vec![]
+            } else {
+                vec![line_string[0], line_string[line_string.len() - 1]]
+            }
+        };
+        if !self.iter().all(|line_string| line_string.is_simple()) {
+            return false;
+        }
+        for (index, line_string) in self.iter().enumerate() {
+            for other in self.iter().skip(index + 1) {
+                let allowed: Vec<[f64; 2]> = end_points(line_string)
+                    .into_iter()
+                    .filter(|coordinate| end_points(other).contains(coordinate))
+                    .collect();
+                for pair in line_string.windows(2) {
+                    let segment = LineSegment::new([pair[0], pair[1]]);
+                    for other_pair in other.windows(2) {
+                        let other_segment = LineSegment::new([other_pair[0], other_pair[1]]);
+                        if segment
+                            .intersection_coordinates(&other_segment)
+                            .iter()
+                            .any(|coordinate| !allowed.contains(coordinate))
+                        {
+                            return false;
+                        }
+                    }
+                }
+            }
+        }
+        true
+    }
+}
+
+impl ConvexHull for MultiLineString {
+    /// The convex hull of the vertices of every `LineString` in this `MultiLineString`.
+    fn convex_hull(&self) -> Result<Polygon, GeometryError> {
+        let coordinates: Vec<[f64; 2]> =
+            self.iter().flat_map(|line| line.iter().copied()).collect();
+        helpers::convex_hull_polygon(&coordinates)
+    }
+}
+
+impl Envelope for MultiLineString {
+    fn envelope(&self) -> Option<Rect> {
+        Rect::from_coordinates(
+            self.iter()
+                .flat_map(|line_string| line_string.iter().copied()),
+        )
+    }
+}
+
+impl Dimensions for MultiLineString {
+    /// A `MultiLineString` is 1-dimensional.
+    fn dimension(&self) -> u8 {
+        1
     }
 }
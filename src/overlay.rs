@@ -0,0 +1,183 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::error::GeometryError;
+use crate::helpers;
+use crate::line_string::{LineString, MultiLineString};
+use crate::polygon::{MultiPolygon, Polygon};
+use crate::topology;
+
+type Edge = [[f64; 2]; 2];
+type EdgeKey = [[u64; 2]; 2];
+
+impl MultiPolygon {
+    /// Dissolve the `Polygon`s in this `MultiPolygon` into the fewest `Polygon`s covering
+    /// the same area.
+    ///
+    /// Edges shared by overlapping or adjacent `Polygon`s disappear, while holes not
+    /// covered by any other `Polygon` are kept.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use auto_gis_with_rust::polygon::MultiPolygon;
+    ///
+    /// let multi_polygon = MultiPolygon::try_from(vec![
+    ///     vec![vec![[0, 0], [2, 0], [2, 2], [0, 2]]],
+    ///     vec![vec![[1, 1], [3, 1], [3, 3], [1, 3]]],
+    /// ]).unwrap();
+    /// let union = multi_polygon.union().unwrap();
+    ///
+    /// assert_eq!(union.len(), 1);
+    /// assert_eq!(union[0].len(), 1);
+    /// assert_eq!(union.area(), 7.);
+    /// ```
+    pub fn union(&self) -> Result<MultiPolygon, GeometryError> {
+        let polygons: Vec<&Polygon> = self.iter().collect();
+        overlay(&[&polygons], |inside| inside[0])
+    }
+}
+
+impl Polygon {
+    /// The part of this `Polygon` also covered by `other`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::polygon::Polygon;
+    ///
+    /// let square_1 = Polygon::new(vec![vec![[0, 0], [2, 0], [2, 2], [0, 2]]]).unwrap();
+    /// let square_2 = Polygon::new(vec![vec![[1, 1], [3, 1], [3, 3], [1, 3]]]).unwrap();
+    ///
+    /// assert_eq!(
+    ///     square_1.intersection(&square_2).unwrap().to_string(),
+    ///     "MULTIPOLYGON (((1 1, 2 1, 2 2, 1 2, 1 1)))",
+    /// );
+    /// ```
+    pub fn intersection(&self, other: &Polygon) -> Result<MultiPolygon, GeometryError> {
+        overlay(&[&[self], &[other]], |inside| inside[0] && inside[1])
+    }
+
+    /// The part of this `Polygon` not covered by `other`.
+    ///
+    /// The result can fall apart into several pieces or gain holes, so it is returned as a
+    /// `MultiPolygon`.
+    ///
+    /// # Examples:
+    ///
+    /// Subtracting a small square from the middle of a larger one leaves a hole.
+    ///
+    /// ```
+    /// use auto_gis_with_rust::polygon::Polygon;
+    ///
+    /// let large = Polygon::new(vec![vec![[0, 0], [3, 0], [3, 3], [0, 3]]]).unwrap();
+    /// let small = Polygon::new(vec![vec![[1, 1], [2, 1], [2, 2], [1, 2]]]).unwrap();
+    ///
+    /// assert_eq!(
+    ///     large.difference(&small).unwrap().to_string(),
+    ///     "MULTIPOLYGON (((0 0, 3 0, 3 3, 0 3, 0 0), (1 1, 1 2, 2 2, 2 1, 1 1)))",
+    /// );
+    /// ```
+    ///
+    /// Subtracting a band across the middle splits it in two.
+    ///
+    /// ```
+    /// # use auto_gis_with_rust::polygon::Polygon;
+    /// # let large = Polygon::new(vec![vec![[0, 0], [3, 0], [3, 3], [0, 3]]]).unwrap();
+    /// let band = Polygon::new(vec![vec![[-1, 1], [4, 1], [4, 2], [-1, 2]]]).unwrap();
+    /// let difference = large.difference(&band).unwrap();
+    ///
+    /// assert_eq!(difference.len(), 2);
+    /// assert_eq!(difference.area(), 6.);
+    /// ```
+    pub fn difference(&self, other: &Polygon) -> Result<MultiPolygon, GeometryError> {
+        overlay(&[&[self], &[other]], |inside| inside[0] && !inside[1])
+    }
+
+    /// The parts of this `Polygon` and `other` covered by exactly one of them.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::polygon::{MultiPolygon, Polygon};
+    ///
+    /// let square_1 = Polygon::new(vec![vec![[0, 0], [2, 0], [2, 2], [0, 2]]]).unwrap();
+    /// let square_2 = Polygon::new(vec![vec![[1, 1], [3, 1], [3, 3], [1, 3]]]).unwrap();
+    ///
+    /// let symmetric_difference = square_1.symmetric_difference(&square_2).unwrap();
+    /// let union = MultiPolygon::new(vec![square_1.clone(), square_2.clone()]).union().unwrap();
+    /// let intersection = square_1.intersection(&square_2).unwrap();
+    ///
+    /// assert_eq!(symmetric_difference.len(), 2);
+    /// assert_eq!(symmetric_difference.area(), union.area() - intersection.area());
+    /// ```
+    pub fn symmetric_difference(&self, other: &Polygon) -> Result<MultiPolygon, GeometryError> {
+        overlay(&[&[self], &[other]], |inside| inside[0] != inside[1])
+    }
+}
+
+/// Combine groups of `Polygon`s, keeping the area where `keep` returns true.
+///
+/// The rings of every `Polygon` are noded and polygonized into the faces of their overlay.
+/// Each face is kept if `keep` returns true when passed, for each group, whether a point
+/// inside the face lies inside any `Polygon` of that group. Edges between two kept faces
+/// are then dropped and the remaining edges polygonized again to merge the kept faces.
+///
+/// The result is only as exact as the noding: crossing points are rounded to the nearest
+/// representable coordinates.
+pub(crate) fn overlay<F: Fn(&[bool]) -> bool>(
+    groups: &[&[&Polygon]],
+    keep: F,
+) -> Result<MultiPolygon, GeometryError> {
+    let in_result = |face: &Polygon| {
+        let point = face.point_on_surface();
+        let inside: Vec<bool> = groups
+            .iter()
+            .map(|group| group.iter().any(|polygon| polygon.contains_point(&point)))
+            .collect();
+        keep(&inside)
+    };
+
+    let rings: Vec<LineString> = groups
+        .iter()
+        .flat_map(|group| group.iter())
+        .flat_map(|polygon| polygon.ring_line_strings())
+        .collect();
+    let faces = topology::polygonize(&topology::node(&MultiLineString::new(rings)));
+    let kept: Vec<&Polygon> = faces.iter().filter(|face| in_result(face)).collect();
+
+    // Every ring of a polygonized face runs with the face on its left, so an edge that
+    // belongs to only one kept face is on the boundary of the result, with the result on
+    // its left.
+    let mut edges: HashMap<EdgeKey, Vec<Edge>> = HashMap::new();
+    for face in &kept {
+        for edge in face.iter().flat_map(|ring| ring.windows(2)) {
+            let mut key = [
+                helpers::coordinate_key(edge[0]),
+                helpers::coordinate_key(edge[1]),
+            ];
+            key.sort();
+            edges.entry(key).or_default().push([edge[0], edge[1]]);
+        }
+    }
+    let mut boundary: Vec<Edge> = edges
+        .into_values()
+        .filter(|edges| edges.len() == 1)
+        .map(|edges| edges[0])
+        .collect();
+    boundary.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let directed: HashSet<EdgeKey> = boundary
+        .iter()
+        .map(|edge| edge.map(helpers::coordinate_key))
+        .collect();
+    let boundary: Vec<LineString> = boundary
+        .into_iter()
+        .map(|edge| LineString::new(edge.to_vec()))
+        .collect::<Result<_, _>>()?;
+
+    let merged = topology::polygonize(&MultiLineString::new(boundary))
+        .into_iter()
+        .filter(|face| directed.contains(&[face[0][0], face[0][1]].map(helpers::coordinate_key)))
+        .collect();
+    Ok(MultiPolygon::new(merged))
+}
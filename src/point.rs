@@ -1,15 +1,41 @@
-use std::{fmt, ops::Deref};
+use std::{
+    collections::HashSet,
+    fmt,
+    ops::{Deref, Index},
+    slice::SliceIndex,
+};
 
 use itertools::Itertools;
 use num_traits::{self, NumCast};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
-use crate::implement_deref;
-use crate::traits::{Geometry, GeometryCollection};
+use crate::error::GeometryError;
+use crate::geometry;
+use crate::line_string::LineSegment;
+use crate::polygon::Polygon;
+use crate::rect::Rect;
+use crate::traits::Curve;
+use crate::traits::{
+    Dimensions, Envelope, EqualsExact, Geometry, GeometryCollection, GeometryType, MapCoordinates,
+};
+use crate::{helpers, implement_deref};
 
 #[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
-pub struct Point([f64; 2]);
+pub struct Point([f64; 2], Option<f64>, Option<f64>);
 
 impl Point {
+    /// The `Point` at `(0, 0)`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// assert_eq!(Point::ORIGIN, Point::new(0, 0));
+    /// ```
+    pub const ORIGIN: Point = Point([0.0, 0.0], None, None);
+
     /// Construct a new `Point`.
     ///
     /// # Examples:
@@ -29,7 +55,74 @@ impl Point {
     pub fn new<T: NumCast, U: NumCast>(x: T, y: U) -> Self {
         let x_float: f64 = num_traits::cast(x).unwrap();
         let y_float: f64 = num_traits::cast(y).unwrap();
-        Point([x_float, y_float])
+        Point([x_float, y_float], None, None)
+    }
+
+    /// Construct a new `Point` from a slice of 2, 3 or 4 values: x and y, then z, then m.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// let flat = [0., 1., 2., 3., 4.];
+    ///
+    /// assert_eq!(Point::from_slice(&flat[..2]).unwrap(), Point::new(0, 1));
+    /// assert_eq!(Point::from_slice(&flat[..3]).unwrap(), Point::new(0, 1).with_z(2.));
+    /// assert_eq!(Point::from_slice(&flat[..4]).unwrap().to_string(), "POINT ZM (0 1 2 3)");
+    /// assert!(Point::from_slice(&flat[..1]).is_err());
+    /// assert!(Point::from_slice(&flat).is_err());
+    /// ```
+    pub fn from_slice(coordinates: &[f64]) -> Result<Self, GeometryError> {
+        match *coordinates {
+            [x, y] => Ok(Point([x, y], None, None)),
+            [x, y, z] => Ok(Point([x, y], Some(z), None)),
+            [x, y, z, m] => Ok(Point([x, y], Some(z), Some(m))),
+            _ => Err(GeometryError::InvalidDimension(coordinates.len())),
+        }
+    }
+
+    /// The x and y coordinates of this `Point` as a slice, without its z or m values.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// assert_eq!(Point::new(0, 1).xy(), &[0., 1.]);
+    /// assert_eq!(Point::new(0, 1).with_z(2.).xy(), &[0., 1.]);
+    /// ```
+    pub fn xy(&self) -> &[f64] {
+        &self.0
+    }
+
+    /// The coordinates of this `Point` in the order [`from_slice`](Point::from_slice)
+    /// reads them: x and y, then z if it has one, then m if it has one.
+    ///
+    /// A `Point` with an m value but no z value gets a NaN z, since the z comes first.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// let point = Point::new(0, 1).with_z(2.);
+    ///
+    /// assert_eq!(Point::new(0, 1).to_vec(), vec![0., 1.]);
+    /// assert_eq!(point.to_vec(), vec![0., 1., 2.]);
+    /// assert_eq!(Point::from_slice(&point.to_vec()).unwrap(), point);
+    /// assert_eq!(point.with_m(3.).to_vec(), vec![0., 1., 2., 3.]);
+    /// assert!(Point::new(0, 1).with_m(3.).to_vec()[2].is_nan());
+    /// ```
+    pub fn to_vec(&self) -> Vec<f64> {
+        let mut coordinates = self.0.to_vec();
+        match (self.1, self.2) {
+            (Some(z), Some(m)) => coordinates.extend([z, m]),
+            (Some(z), None) => coordinates.push(z),
+            (None, Some(m)) => coordinates.extend([f64::NAN, m]),
+            (None, None) => {}
+        }
+        coordinates
     }
 
     /// Return the x-coordinate value for this `Point`.
@@ -66,20 +159,184 @@ impl Point {
 
     /// Return the z-coordinate value for this `Point`, if it has one.
     pub fn z(&self) -> Option<f64> {
-        if self.len() <= 2 {
-            None
-        } else {
-            Some(self[2])
-        }
+        self.1
     }
 
     /// Return the m-coordinate value for this `Point`, if it has one.
     pub fn m(&self) -> Option<f64> {
-        if self.len() <= 3 {
-            None
-        } else {
-            Some(self[3])
+        self.2
+    }
+
+    /// Return a copy of this `Point` with the z-coordinate value `z`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// let point = Point::new(1, 2).with_z(3.);
+    ///
+    /// assert_eq!(point.z(), Some(3.));
+    /// assert_eq!(point.to_string(), "POINT Z (1 2 3)");
+    /// ```
+    pub fn with_z(&self, z: f64) -> Point {
+        Point(self.0, Some(z), self.2)
+    }
+
+    /// Return a copy of this `Point` with the m-coordinate (measure) value `m`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// let point = Point::new(1, 2).with_m(4.);
+    ///
+    /// assert_eq!(point.m(), Some(4.));
+    /// assert_eq!(point.to_string(), "POINT M (1 2 4)");
+    /// assert_eq!(point.with_z(3.).to_string(), "POINT ZM (1 2 3 4)");
+    /// ```
+    pub fn with_m(&self, m: f64) -> Point {
+        Point(self.0, self.1, Some(m))
+    }
+
+    /// The Euclidean distance between this `Point` and another `Point`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// let point_0 = Point::new(0, 0);
+    /// let point_1 = Point::new(3, 4);
+    ///
+    /// assert_eq!(point_0.distance(&point_1), 5.);
+    /// ```
+    pub fn distance(&self, other: &Point) -> f64 {
+        (other.x() - self.x()).hypot(other.y() - self.y())
+    }
+
+    /// The boundary of a `Point` is always empty.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// let point = Point::new(0.0, 1.0);
+    ///
+    /// assert_eq!(point.boundary().to_string(), "MULTIPOINT ()");
+    /// ```
+    pub fn boundary(&self) -> geometry::Geometry {
+        geometry::Geometry::MultiPoint(MultiPoint::new(vec![]))
+    }
+
+    /// Returns true if this `Point` is at `(0, 0)`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// assert!(Point::new(0, 0).is_origin());
+    /// assert!(!Point::new(0, 1).is_origin());
+    /// ```
+    pub fn is_origin(&self) -> bool {
+        self.x() == 0. && self.y() == 0.
+    }
+
+    /// The `Point` a `fraction` of the way from this `Point` to `other` along the great
+    /// circle joining them, treating both as longitude and latitude in degrees on a sphere.
+    ///
+    /// A `fraction` of `0.0` gives this `Point` and `1.0` gives `other`. The great circle
+    /// between two antipodal `Point`s is undefined, so this `Point` is returned for them,
+    /// as it is for two `Point`s at the same location.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// let midpoint = Point::new(10, 0).slerp(&Point::new(50, 0), 0.5);
+    ///
+    /// assert!((midpoint.x() - 30.).abs() < 1e-9);
+    /// assert!(midpoint.y().abs() < 1e-9);
+    /// ```
+    ///
+    /// The shortest path between two `Point`s at the same latitude bends towards the pole.
+    ///
+    /// ```
+    /// # use auto_gis_with_rust::point::Point;
+    /// let midpoint = Point::new(-90, 45).slerp(&Point::new(90, 45), 0.5);
+    ///
+    /// assert!((midpoint.y() - 90.).abs() < 1e-9);
+    /// ```
+    pub fn slerp(&self, other: &Point, fraction: f64) -> Point {
+        let to_vector = |point: &Point| {
+            let (longitude, latitude) = (point.x().to_radians(), point.y().to_radians());
+            [
+                latitude.cos() * longitude.cos(),
+                latitude.cos() * longitude.sin(),
+                latitude.sin(),
+            ]
+        };
+        let (a, b) = (to_vector(self), to_vector(other));
+        let cross = [
+            a[1] * b[2] - a[2] * b[1],
+            a[2] * b[0] - a[0] * b[2],
+            a[0] * b[1] - a[1] * b[0],
+        ];
+        let sine = cross.iter().map(|value| value * value).sum::<f64>().sqrt();
+        let cosine = a[0] * b[0] + a[1] * b[1] + a[2] * b[2];
+        if sine < 1e-12 {
+            return Point::new(self.x(), self.y());
         }
+        let angle = sine.atan2(cosine);
+        let weight_a = ((1. - fraction) * angle).sin() / sine;
+        let weight_b = (fraction * angle).sin() / sine;
+        let [x, y, z] = [0, 1, 2].map(|axis| weight_a * a[axis] + weight_b * b[axis]);
+        Point::new(y.atan2(x).to_degrees(), z.atan2(x.hypot(y)).to_degrees())
+    }
+
+    /// The initial bearing in degrees from this `Point` to `other` along the great circle
+    /// joining them, treating both as longitude and latitude in degrees on a sphere.
+    ///
+    /// Bearings are measured clockwise from north, from `0.0` up to but not including
+    /// `360.0`. The bearing between two `Point`s at the same location is `0.0`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// assert_eq!(Point::new(0, 0).bearing(&Point::new(0, 10)), 0.);
+    /// assert_eq!(Point::new(0, 0).bearing(&Point::new(10, 0)), 90.);
+    /// assert_eq!(Point::new(0, 0).bearing(&Point::new(-10, 0)), 270.);
+    /// assert!((Point::new(0, 60).bearing(&Point::new(10, 60)) - 85.67).abs() < 0.01);
+    /// ```
+    pub fn bearing(&self, other: &Point) -> f64 {
+        let (latitude_0, latitude_1) = (self.y().to_radians(), other.y().to_radians());
+        let delta = (other.x() - self.x()).to_radians();
+        let y = delta.sin() * latitude_1.cos();
+        let x =
+            latitude_0.cos() * latitude_1.sin() - latitude_0.sin() * latitude_1.cos() * delta.cos();
+        y.atan2(x).to_degrees().rem_euclid(360.)
+    }
+
+    /// A copy of this `Point` with its longitude, the x coordinate in degrees, wrapped into
+    /// the range `-180` to `180`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// assert_eq!(Point::new(190, 10).normalize_longitude(), Point::new(-170, 10));
+    /// assert_eq!(Point::new(-200, 10).normalize_longitude(), Point::new(160, 10));
+    /// assert_eq!(Point::new(180, 10).normalize_longitude(), Point::new(180, 10));
+    /// ```
+    pub fn normalize_longitude(&self) -> Point {
+        self.wrap_longitudes()
     }
 }
 
@@ -87,7 +344,17 @@ implement_deref!(Point, [f64; 2]);
 
 impl fmt::Display for Point {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "POINT ({} {})", self.x(), self.y())
+        let tag = match (self.z(), self.m()) {
+            (None, None) => "",
+            (Some(_), None) => " Z",
+            (None, Some(_)) => " M",
+            (Some(_), Some(_)) => " ZM",
+        };
+        write!(f, "POINT{} ({} {}", tag, self.x(), self.y())?;
+        for value in [self.z(), self.m()].into_iter().flatten() {
+            write!(f, " {}", value)?;
+        }
+        write!(f, ")")
     }
 }
 
@@ -132,7 +399,74 @@ impl Geometry for Point {
     }
 }
 
-#[derive(Debug, PartialEq, PartialOrd)]
+impl Envelope for Point {
+    /// A `Point`'s envelope is a `Rect` with no extent.
+    fn envelope(&self) -> Option<Rect> {
+        Some(Rect::new(self.0, self.0))
+    }
+}
+
+impl Dimensions for Point {
+    /// A `Point` is 0-dimensional.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::Point;
+    /// use auto_gis_with_rust::traits::Dimensions;
+    ///
+    /// let point = Point::new(0.0, 1.0);
+    ///
+    /// assert_eq!(point.dimension(), 0);
+    /// assert_eq!(point.coordinate_dimension(), 2);
+    /// ```
+    fn dimension(&self) -> u8 {
+        0
+    }
+
+    fn coordinate_dimension(&self) -> u8 {
+        2 + self.z().is_some() as u8 + self.m().is_some() as u8
+    }
+}
+
+impl GeometryType for Point {
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::Point;
+    /// use auto_gis_with_rust::traits::GeometryType;
+    ///
+    /// assert_eq!(Point::new(0.0, 1.0).geometry_type(), "Point");
+    /// ```
+    fn geometry_type(&self) -> &'static str {
+        "Point"
+    }
+}
+
+impl EqualsExact for Point {
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::Point;
+    /// use auto_gis_with_rust::traits::EqualsExact;
+    ///
+    /// let point = Point::new(0., 1.);
+    ///
+    /// assert!(point.equals_exact(&Point::new(0.001, 1.), 0.01));
+    /// assert!(!point.equals_exact(&Point::new(0.1, 1.), 0.01));
+    /// ```
+    fn equals_exact(&self, other: &Self, tolerance: f64) -> bool {
+        self.distance(other) <= tolerance
+    }
+}
+
+impl MapCoordinates for Point {
+    fn map_coordinates<F: Fn([f64; 2]) -> [f64; 2]>(&self, function: F) -> Self {
+        Point(function(self.0), self.1, self.2)
+    }
+}
+
+#[derive(Default, PartialEq, PartialOrd, Clone)]
 pub struct MultiPoint(pub Vec<Point>);
 
 impl MultiPoint {
@@ -154,6 +488,334 @@ impl MultiPoint {
     pub fn new(points: Vec<Point>) -> Self {
         MultiPoint(points)
     }
+
+    /// The boundary of a `MultiPoint` is always empty.
+    pub fn boundary(&self) -> geometry::Geometry {
+        geometry::Geometry::MultiPoint(MultiPoint::new(vec![]))
+    }
+
+    /// The smallest convex `Polygon` containing every `Point` in this `MultiPoint`.
+    ///
+    /// The exterior ring runs counter-clockwise. Returns `GeometryError::TooFewCoords` if
+    /// there are fewer than three non-collinear `Point`s.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::MultiPoint;
+    ///
+    /// let multi_point = MultiPoint::from(vec![[0, 0], [2, 0], [1, 1], [2, 2], [0, 2]]);
+    /// let convex_hull = multi_point.convex_hull().unwrap();
+    ///
+    /// assert_eq!(convex_hull.to_string(), "POLYGON ((0 0, 2 0, 2 2, 0 2, 0 0))");
+    /// ```
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::MultiPoint;
+    ///
+    /// let multi_point = MultiPoint::from(vec![[0, 0], [1, 1], [2, 2]]);
+    ///
+    /// assert!(multi_point.convex_hull().is_err());
+    /// ```
+    pub fn convex_hull(&self) -> Result<Polygon, GeometryError> {
+        let coordinates: Vec<[f64; 2]> = self.iter().map(|point| point.0).collect();
+        helpers::convex_hull_polygon(&coordinates)
+    }
+
+    /// The envelope of this `MultiPoint` as a `Polygon`, or `None` if it is empty.
+    ///
+    /// This is a quicker but looser footprint than the
+    /// [`convex_hull`](MultiPoint::convex_hull), and is the same as
+    /// [`envelope_polygon`](Envelope::envelope_polygon). Use
+    /// [`envelope`](Envelope::envelope) for the box as a `Rect`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::MultiPoint;
+    /// use auto_gis_with_rust::rect::Rect;
+    /// use auto_gis_with_rust::traits::Envelope;
+    ///
+    /// let observations = MultiPoint::from(vec![[2., 1.], [4.5, 3.], [3., -1.], [1., 2.]]);
+    ///
+    /// assert_eq!(observations.envelope(), Some(Rect::new([1., -1.], [4.5, 3.])));
+    /// assert_eq!(
+    ///     observations.bounding_polygon().unwrap().to_string(),
+    ///     "POLYGON ((1 -1, 4.5 -1, 4.5 3, 1 3, 1 -1))",
+    /// );
+    /// assert_eq!(MultiPoint::new(vec![]).bounding_polygon(), None);
+    /// ```
+    pub fn bounding_polygon(&self) -> Option<Polygon> {
+        self.envelope_polygon()
+    }
+
+    /// A `Polygon` enclosing every `Point` in this `MultiPoint` that can follow concave
+    /// indentations more tightly than the convex hull.
+    ///
+    /// Starting from the convex hull, each edge is repeatedly "dug" inwards to the nearest
+    /// `Point` not yet on the hull, as described by Park and Oh (2012). An edge is only dug
+    /// if its length divided by the distance from the candidate `Point` to the nearer of
+    /// the edge's ends exceeds `concavity`, and if doing so neither crosses another edge
+    /// nor leaves a `Point` outside. Smaller values of `concavity` produce tighter, more
+    /// concave hulls; as `concavity` grows, the result approaches the convex hull.
+    ///
+    /// Returns `GeometryError::TooFewCoords` if there are fewer than three non-collinear
+    /// `Point`s.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::MultiPoint;
+    ///
+    /// let multi_point = MultiPoint::from(vec![[0., 0.], [4., 0.], [4., 4.], [2., 2.5], [0., 4.]]);
+    ///
+    /// let concave_hull = multi_point.concave_hull(1.).unwrap();
+    /// assert_eq!(concave_hull.to_string(), "POLYGON ((0 0, 4 0, 4 4, 2 2.5, 0 4, 0 0))");
+    ///
+    /// let convex_like_hull = multi_point.concave_hull(100.).unwrap();
+    /// assert_eq!(convex_like_hull, multi_point.convex_hull().unwrap());
+    /// ```
+    pub fn concave_hull(&self, concavity: f64) -> Result<Polygon, GeometryError> {
+        let coordinates: Vec<[f64; 2]> = self.iter().map(|point| point.0).collect();
+        let mut hull = helpers::convex_hull(&coordinates);
+        if hull.len() < 3 {
+            return Err(GeometryError::TooFewCoords(hull.len()));
+        }
+        let mut inner: Vec<[f64; 2]> = coordinates
+            .into_iter()
+            .filter(|coordinate| !hull.contains(coordinate))
+            .collect();
+        inner.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        inner.dedup();
+
+        let mut index = 0;
+        while index < hull.len() {
+            let start = hull[index];
+            let end = hull[(index + 1) % hull.len()];
+            let edge = LineSegment::new([start, end]);
+            let candidate = inner
+                .iter()
+                .enumerate()
+                .map(|(position, coordinate)| {
+                    (
+                        position,
+                        *coordinate,
+                        edge.distance(&Point::from(*coordinate)),
+                    )
+                })
+                .filter(|(_, coordinate, distance)| {
+                    Self::nearest_hull_edge_distance(&hull, *coordinate) >= *distance
+                })
+                .min_by(|(_, _, a), (_, _, b)| a.partial_cmp(b).unwrap());
+            let mut dug = false;
+            if let Some((position, coordinate, _)) = candidate {
+                let point = Point::from(coordinate);
+                let decision_distance = point
+                    .distance(&Point::from(start))
+                    .min(point.distance(&Point::from(end)));
+                dug = edge.length() / decision_distance > concavity
+                    && !Self::digging_crosses_hull(&hull, index, coordinate)
+                    && !Self::digging_uncovers(&inner, position, [start, coordinate, end]);
+                if dug {
+                    hull.insert(index + 1, coordinate);
+                    inner.remove(position);
+                }
+            }
+            if !dug {
+                index += 1;
+            }
+        }
+        Polygon::new(vec![hull])
+    }
+
+    /// The distance from `coordinate` to the nearest edge of the hull.
+    fn nearest_hull_edge_distance(hull: &[[f64; 2]], coordinate: [f64; 2]) -> f64 {
+        (0..hull.len())
+            .map(|index| {
+                LineSegment::new([hull[index], hull[(index + 1) % hull.len()]])
+                    .distance(&Point::from(coordinate))
+            })
+            .fold(f64::INFINITY, f64::min)
+    }
+
+    /// Whether replacing the hull edge starting at `index` with two edges through
+    /// `coordinate` would cross any other edge of the hull.
+    fn digging_crosses_hull(hull: &[[f64; 2]], index: usize, coordinate: [f64; 2]) -> bool {
+        let start = hull[index];
+        let end = hull[(index + 1) % hull.len()];
+        let crosses = |a: [f64; 2], b: [f64; 2], c: [f64; 2], d: [f64; 2]| {
+            helpers::cross_product(a, b, c) * helpers::cross_product(a, b, d) < 0.
+                && helpers::cross_product(c, d, a) * helpers::cross_product(c, d, b) < 0.
+        };
+        (0..hull.len())
+            .filter(|other| *other != index)
+            .any(|other| {
+                let c = hull[other];
+                let d = hull[(other + 1) % hull.len()];
+                crosses(start, coordinate, c, d) || crosses(coordinate, end, c, d)
+            })
+    }
+
+    /// Whether cutting the `triangle` out of the hull would leave any of the `inner`
+    /// coordinates, other than the one at `skip`, outside it.
+    fn digging_uncovers(inner: &[[f64; 2]], skip: usize, triangle: [[f64; 2]; 3]) -> bool {
+        let [a, b, c] = triangle;
+        inner
+            .iter()
+            .enumerate()
+            .filter(|(position, _)| *position != skip)
+            .any(|(_, coordinate)| {
+                let turns = [
+                    helpers::cross_product(a, b, *coordinate),
+                    helpers::cross_product(b, c, *coordinate),
+                    helpers::cross_product(c, a, *coordinate),
+                ];
+                turns.iter().all(|turn| *turn >= 0.) || turns.iter().all(|turn| *turn <= 0.)
+            })
+    }
+
+    /// A `MultiPoint` of the distinct `Point`s in this one, in the order they first appear.
+    ///
+    /// `Point`s are compared by their x and y coordinates only, and the first of several
+    /// `Point`s at the same location is the one kept.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::MultiPoint;
+    ///
+    /// let multi_point = MultiPoint::from(vec![[1, 0], [0, 0], [1, 0], [0, 0]]);
+    ///
+    /// assert_eq!(multi_point.unique().to_string(), "MULTIPOINT ((1 0), (0 0))");
+    /// ```
+    pub fn unique(&self) -> MultiPoint {
+        let mut seen = HashSet::new();
+        MultiPoint(
+            self.iter()
+                .filter(|point| seen.insert(helpers::coordinate_key(point.0)))
+                .copied()
+                .collect(),
+        )
+    }
+
+    /// The mean of the distinct `Point`s in this `MultiPoint`, or `None` if it is empty.
+    ///
+    /// Unlike [`centroid`](Geometry::centroid), `Point`s repeated at the same location
+    /// are only counted once, so they do not pull the result towards themselves.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::MultiPoint;
+    /// use auto_gis_with_rust::traits::Geometry;
+    ///
+    /// let multi_point = MultiPoint::from(vec![[0, 0], [0, 0], [2, 0]]);
+    ///
+    /// assert_eq!(multi_point.centroid_unique().unwrap().to_string(), "POINT (1 0)");
+    /// assert_eq!(multi_point.centroid().x(), 2. / 3.);
+    /// assert_eq!(MultiPoint::new(vec![]).centroid_unique(), None);
+    /// ```
+    pub fn centroid_unique(&self) -> Option<Point> {
+        if self.is_empty() {
+            return None;
+        }
+        Some(self.unique().centroid())
+    }
+
+    /// Group the `Point`s in this `MultiPoint` into clusters with DBSCAN, returning one
+    /// `MultiPoint` per cluster.
+    ///
+    /// A `Point` with at least `min_pts` `Point`s, itself included, within `eps` of it is a
+    /// core `Point`. Core `Point`s within `eps` of each other share a cluster, along with
+    /// every other `Point` within `eps` of one of them. `Point`s in no cluster are noise and
+    /// are dropped. Clusters are ordered by their first `Point`, and keep the order of their
+    /// `Point`s in this `MultiPoint`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::MultiPoint;
+    ///
+    /// let multi_point = MultiPoint::from(vec![
+    ///     [0., 0.], [10., 10.], [0.5, 0.], [0., 0.5], [10.5, 10.], [50., 50.], [10., 10.5],
+    /// ]);
+    /// let clusters = multi_point.cluster_dbscan(1., 3);
+    ///
+    /// assert_eq!(clusters.len(), 2);
+    /// assert_eq!(clusters[0].to_string(), "MULTIPOINT ((0 0), (0.5 0), (0 0.5))");
+    /// assert_eq!(clusters[1].to_string(), "MULTIPOINT ((10 10), (10.5 10), (10 10.5))");
+    /// ```
+    pub fn cluster_dbscan(&self, eps: f64, min_pts: usize) -> Vec<MultiPoint> {
+        let neighbours = |index: usize| -> Vec<usize> {
+            (0..self.len())
+                .filter(|other| self[index].distance(&self[*other]) <= eps)
+                .collect()
+        };
+        let mut labels: Vec<Option<usize>> = vec![None; self.len()];
+        let mut visited = vec![false; self.len()];
+        let mut count = 0;
+        for index in 0..self.len() {
+            if visited[index] {
+                continue;
+            }
+            visited[index] = true;
+            let reachable = neighbours(index);
+            if reachable.len() < min_pts {
+                continue;
+            }
+            labels[index] = Some(count);
+            // Label each unlabelled neighbour as it is found, and queue it only if it has
+            // not been visited, so every `Point` is queued at most once.
+            let mut queue = Vec::new();
+            let mut claim = |reachable: Vec<usize>, queue: &mut Vec<usize>| {
+                for neighbour in reachable {
+                    if labels[neighbour].is_none() {
+                        labels[neighbour] = Some(count);
+                        if !visited[neighbour] {
+                            visited[neighbour] = true;
+                            queue.push(neighbour);
+                        }
+                    }
+                }
+            };
+            claim(reachable, &mut queue);
+            while let Some(neighbour) = queue.pop() {
+                let reachable = neighbours(neighbour);
+                if reachable.len() >= min_pts {
+                    claim(reachable, &mut queue);
+                }
+            }
+            count += 1;
+        }
+        let mut clusters = vec![MultiPoint(vec![]); count];
+        for (point, label) in self.iter().zip(labels) {
+            if let Some(label) = label {
+                clusters[label].0.push(*point);
+            }
+        }
+        clusters
+    }
+
+    /// The `Point`s that make up this `MultiPoint`.
+    pub fn points(&self) -> &[Point] {
+        &self.0
+    }
+
+    /// Consume this `MultiPoint`, returning its owned `Point`s.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::{MultiPoint, Point};
+    ///
+    /// let multi_point = MultiPoint::from(vec![[0, 0], [1, 1]]);
+    ///
+    /// assert_eq!(multi_point.points().len(), 2);
+    /// assert_eq!(multi_point.into_points(), vec![Point::new(0, 0), Point::new(1, 1)]);
+    /// ```
+    pub fn into_points(self) -> Vec<Point> {
+        self.0
+    }
 }
 
 implement_deref!(MultiPoint, Vec<Point>);
@@ -167,6 +829,33 @@ impl fmt::Display for MultiPoint {
     }
 }
 
+impl fmt::Debug for MultiPoint {
+    /// Summarize this `MultiPoint` as its number of `Point`s and its envelope, or list
+    /// every `Point` in the alternate `{:#?}` form.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::MultiPoint;
+    ///
+    /// let multi_point = MultiPoint::from(vec![[0, 1], [2, -1]]);
+    ///
+    /// assert_eq!(
+    ///     format!("{:?}", multi_point),
+    ///     "MultiPoint { points: 2, bbox: [[0.0, -1.0], [2.0, 1.0]] }",
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        helpers::debug_geometry(
+            f,
+            "MultiPoint",
+            &[("points", self.len())],
+            self.envelope(),
+            &self.0,
+        )
+    }
+}
+
 impl<T: NumCast + Copy> From<Vec<[T; 2]>> for MultiPoint {
     /// Construct a `MultiPoint` from a vector of 2-element arrays.
     ///
@@ -185,6 +874,125 @@ impl<T: NumCast + Copy> From<Vec<[T; 2]>> for MultiPoint {
     }
 }
 
+impl From<Vec<Point>> for MultiPoint {
+    /// Construct a `MultiPoint` from a vector of `Point`s, without copying them.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::{MultiPoint, Point};
+    ///
+    /// let multi_point = MultiPoint::from(vec![Point::new(0, 0), Point::new(1, 0)]);
+    ///
+    /// assert_eq!("MULTIPOINT ((0 0), (1 0))", multi_point.to_string());
+    /// ```
+    fn from(points: Vec<Point>) -> Self {
+        MultiPoint(points)
+    }
+}
+
+impl From<MultiPoint> for Vec<Point> {
+    /// Consume a `MultiPoint`, returning its `Point`s without copying them.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::{MultiPoint, Point};
+    ///
+    /// let points: Vec<Point> = MultiPoint::from(vec![[0, 0], [1, 0]]).into();
+    ///
+    /// assert_eq!(points, vec![Point::new(0, 0), Point::new(1, 0)]);
+    /// ```
+    fn from(multi_point: MultiPoint) -> Self {
+        multi_point.0
+    }
+}
+
+impl<I: SliceIndex<[Point]>> Index<I> for MultiPoint {
+    type Output = I::Output;
+
+    /// The `Point` at `index`, or the slice of `Point`s in a range of indices, panicking if
+    /// `index` is out of bounds.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::{MultiPoint, Point};
+    ///
+    /// let multi_point = MultiPoint::from(vec![[0, 0], [1, 0]]);
+    ///
+    /// assert_eq!(multi_point[1], Point::new(1, 0));
+    /// ```
+    fn index(&self, index: I) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl Extend<Point> for MultiPoint {
+    /// Append the `Point`s yielded by `iter` to this `MultiPoint`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::{MultiPoint, Point};
+    ///
+    /// let mut multi_point = MultiPoint::default();
+    /// multi_point.extend(vec![Point::new(0, 0), Point::new(1, 0)]);
+    ///
+    /// assert_eq!(multi_point.to_string(), "MULTIPOINT ((0 0), (1 0))");
+    /// ```
+    fn extend<I: IntoIterator<Item = Point>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
+impl IntoIterator for MultiPoint {
+    type Item = Point;
+    type IntoIter = std::vec::IntoIter<Point>;
+
+    /// Consume this `MultiPoint`, yielding its owned `Point`s.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::{MultiPoint, Point};
+    ///
+    /// let multi_point = MultiPoint::from(vec![[0.0, 0.0], [1.0, 0.0]]);
+    /// let points: Vec<Point> = multi_point.into_iter().collect();
+    ///
+    /// assert_eq!(points, vec![Point::new(0, 0), Point::new(1, 0)]);
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a MultiPoint {
+    type Item = Point;
+    type IntoIter = std::iter::Copied<std::slice::Iter<'a, Point>>;
+
+    /// Iterate over copies of the `Point`s in this `MultiPoint`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::MultiPoint;
+    ///
+    /// let multi_point = MultiPoint::from(vec![[0.0, 0.0], [1.0, 0.0]]);
+    ///
+    /// let mut sum_x = 0.;
+    /// for point in &multi_point {
+    ///     sum_x += point.x();
+    /// }
+    ///
+    /// assert_eq!(sum_x, 1.);
+    /// assert_eq!(multi_point.len(), 2);
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter().copied()
+    }
+}
+
 impl GeometryCollection<Point> for MultiPoint {
     /// Returns the number of `Point`s in this `MultiPoint` collection.
     ///
@@ -278,3 +1086,72 @@ impl Geometry for MultiPoint {
         true
     }
 }
+
+impl GeometryType for MultiPoint {
+    fn geometry_type(&self) -> &'static str {
+        "MultiPoint"
+    }
+}
+
+impl EqualsExact for MultiPoint {
+    fn equals_exact(&self, other: &Self, tolerance: f64) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(point, other)| point.equals_exact(other, tolerance))
+    }
+}
+
+impl MapCoordinates for MultiPoint {
+    fn map_coordinates<F: Fn([f64; 2]) -> [f64; 2]>(&self, function: F) -> Self {
+        MultiPoint(
+            self.iter()
+                .map(|point| point.map_coordinates(&function))
+                .collect(),
+        )
+    }
+
+    #[cfg(feature = "parallel")]
+    fn par_map_coordinates<F: Fn([f64; 2]) -> [f64; 2] + Sync + Send>(&self, function: F) -> Self {
+        MultiPoint(
+            self.par_iter()
+                .map(|point| point.par_map_coordinates(&function))
+                .collect(),
+        )
+    }
+}
+
+impl Envelope for MultiPoint {
+    /// The smallest `Rect` containing every `Point`, or `None` for an empty `MultiPoint`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::MultiPoint;
+    /// use auto_gis_with_rust::rect::Rect;
+    /// use auto_gis_with_rust::traits::Envelope;
+    ///
+    /// let multi_point = MultiPoint::from(vec![[0, 3], [2, 1], [1, 2]]);
+    ///
+    /// assert_eq!(multi_point.envelope(), Some(Rect::new([0, 1], [2, 3])));
+    /// assert_eq!(MultiPoint::new(vec![]).envelope(), None);
+    /// ```
+    fn envelope(&self) -> Option<Rect> {
+        Rect::from_coordinates(self.iter().map(|point| point.0))
+    }
+}
+
+impl Dimensions for MultiPoint {
+    /// A `MultiPoint` is 0-dimensional.
+    fn dimension(&self) -> u8 {
+        0
+    }
+
+    fn coordinate_dimension(&self) -> u8 {
+        self.iter()
+            .map(|point| point.coordinate_dimension())
+            .max()
+            .unwrap_or(2)
+    }
+}
@@ -1,11 +1,25 @@
-use std::{convert::TryFrom, fmt, ops::Deref};
+use std::{
+    convert::TryFrom,
+    fmt,
+    ops::{Deref, Index},
+    slice::SliceIndex,
+};
 
 use itertools::Itertools;
 use num_traits::NumCast;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
+use crate::geometry::Geometry;
+use crate::line_string::{densify_coordinates, LineSegment, LineString, MultiLineString};
+use crate::point::Point;
+use crate::rect::Rect;
+use crate::traits::{
+    self, ConvexHull, Dimensions, Envelope, EqualsExact, GeometryType, MapCoordinates,
+};
 use crate::{error::GeometryError, helpers, implement_deref};
 
-#[derive(Debug, PartialEq, PartialOrd)]
+#[derive(Debug, PartialEq, PartialOrd, Clone)]
 pub struct PolygonRing(Vec<[f64; 2]>);
 
 impl PolygonRing {
@@ -37,11 +51,169 @@ impl PolygonRing {
             }
         }
     }
+
+    /// The signed area enclosed by this `PolygonRing`, computed with the shoelace formula.
+    ///
+    /// The area is positive if the ring runs counter-clockwise and negative if it runs
+    /// clockwise.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::polygon::PolygonRing;
+    ///
+    /// let counter_clockwise = PolygonRing::new(vec![[0, 0], [2, 0], [2, 2], [0, 2]]).unwrap();
+    /// let clockwise = PolygonRing::new(vec![[0, 0], [0, 2], [2, 2], [2, 0]]).unwrap();
+    ///
+    /// assert_eq!(counter_clockwise.signed_area(), 4.);
+    /// assert_eq!(clockwise.signed_area(), -4.);
+    /// ```
+    pub fn signed_area(&self) -> f64 {
+        helpers::signed_area(self)
+    }
+
+    /// The `LineSegment`s between each pair of consecutive vertices of this `PolygonRing`,
+    /// including the segment closing it.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineSegment;
+    /// use auto_gis_with_rust::polygon::PolygonRing;
+    ///
+    /// let ring = PolygonRing::new(vec![[0, 0], [1, 0], [1, 1], [0, 1]]).unwrap();
+    /// let segments = ring.segments();
+    ///
+    /// assert_eq!(segments.len(), 4);
+    /// assert_eq!(segments[3], LineSegment::new([[0, 1], [0, 0]]));
+    /// ```
+    pub fn segments(&self) -> Vec<LineSegment> {
+        self.windows(2)
+            .map(|pair| LineSegment::new([pair[0], pair[1]]))
+            .collect()
+    }
+
+    /// A closed `LineString` through the vertices of this `PolygonRing`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::polygon::PolygonRing;
+    /// use auto_gis_with_rust::traits::Curve;
+    ///
+    /// let ring = PolygonRing::new(vec![[0, 0], [1, 0], [1, 1]]).unwrap();
+    /// let line_string = ring.as_line_string();
+    ///
+    /// assert_eq!(line_string.to_string(), "LINESTRING (0 0, 1 0, 1 1, 0 0)");
+    /// assert!(line_string.is_ring());
+    /// ```
+    pub fn as_line_string(&self) -> LineString {
+        LineString::new(self.to_vec()).unwrap()
+    }
+
+    /// The coordinates of this `PolygonRing`, including the closing vertex, packed into a
+    /// single vector, as `[x0, y0, x1, y1, ...]`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::polygon::PolygonRing;
+    ///
+    /// let ring = PolygonRing::new(vec![[0, 0], [1, 0], [1, 1]]).unwrap();
+    ///
+    /// assert_eq!(ring.coords_flat(), vec![0., 0., 1., 0., 1., 1., 0., 0.]);
+    /// assert_eq!(PolygonRing::from_coords_flat(&ring.coords_flat()).unwrap(), ring);
+    /// ```
+    pub fn coords_flat(&self) -> Vec<f64> {
+        self.iter().flatten().copied().collect()
+    }
+
+    /// Construct a new `PolygonRing` from coordinates packed as `[x0, y0, x1, y1, ...]`,
+    /// closing it if the last coordinate differs from the first.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::polygon::PolygonRing;
+    ///
+    /// let ring = PolygonRing::from_coords_flat(&[0., 0., 1., 0., 1., 1.]).unwrap();
+    ///
+    /// assert_eq!(ring.to_string(), "LINEARRING (0 0, 1 0, 1 1, 0 0)");
+    /// assert!(PolygonRing::from_coords_flat(&[0., 0., 1., 0., 1.]).is_err());
+    /// ```
+    pub fn from_coords_flat(coordinates: &[f64]) -> Result<Self, GeometryError> {
+        if !coordinates.len().is_multiple_of(2) {
+            return Err(GeometryError::OddCoordinateCount(coordinates.len()));
+        }
+        PolygonRing::new(
+            coordinates
+                .chunks_exact(2)
+                .map(|pair| [pair[0], pair[1]])
+                .collect(),
+        )
+    }
 }
 
 implement_deref!(PolygonRing, Vec<[f64; 2]>);
 
-#[derive(Debug, PartialEq, PartialOrd)]
+impl fmt::Display for PolygonRing {
+    /// Format this `PolygonRing` as a `LINEARRING`, the form used for rings by some WKT
+    /// dialects, with the closing vertex repeated.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::polygon::PolygonRing;
+    ///
+    /// let ring = PolygonRing::new(vec![[0, 0], [1, 0], [1, 1]]).unwrap();
+    ///
+    /// assert_eq!(ring.to_string(), "LINEARRING (0 0, 1 0, 1 1, 0 0)");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let points = self.iter().format_with(", ", |point, f| {
+            f(&format_args!("{} {}", point[0], point[1]))
+        });
+        write!(f, "LINEARRING ({})", points)
+    }
+}
+
+impl Envelope for PolygonRing {
+    fn envelope(&self) -> Option<Rect> {
+        Rect::from_coordinates(self.iter().copied())
+    }
+}
+
+impl Dimensions for PolygonRing {
+    /// A `PolygonRing` is a curve, so it is 1-dimensional.
+    fn dimension(&self) -> u8 {
+        1
+    }
+}
+
+impl GeometryType for PolygonRing {
+    fn geometry_type(&self) -> &'static str {
+        "LinearRing"
+    }
+}
+
+impl EqualsExact for PolygonRing {
+    fn equals_exact(&self, other: &Self, tolerance: f64) -> bool {
+        helpers::coordinates_equal(self, other, tolerance)
+    }
+}
+
+impl MapCoordinates for PolygonRing {
+    fn map_coordinates<F: Fn([f64; 2]) -> [f64; 2]>(&self, function: F) -> Self {
+        PolygonRing(self.iter().copied().map(function).collect())
+    }
+
+    #[cfg(feature = "parallel")]
+    fn par_map_coordinates<F: Fn([f64; 2]) -> [f64; 2] + Sync + Send>(&self, function: F) -> Self {
+        PolygonRing(self.par_iter().copied().map(function).collect())
+    }
+}
+
+#[derive(PartialEq, PartialOrd, Clone)]
 pub struct Polygon(Vec<PolygonRing>);
 
 impl Polygon {
@@ -70,10 +242,576 @@ impl Polygon {
     pub fn new<T: NumCast>(rings: Vec<Vec<[T; 2]>>) -> Result<Self, GeometryError> {
         let polygon_rings: Vec<PolygonRing> = rings
             .into_iter()
-            .map(|ring| PolygonRing::new(ring).unwrap())
-            .collect();
+            .map(PolygonRing::new)
+            .collect::<Result<_, _>>()?;
         Ok(Polygon(polygon_rings))
     }
+
+    /// The boundary of this `Polygon`: a `MultiLineString` made of its rings.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::polygon::Polygon;
+    ///
+    /// let polygon = Polygon::new(vec![
+    ///     vec![[0, 0], [0, 3], [3, 3], [3, 0]],
+    ///     vec![[1, 1], [2, 1], [2, 2], [1, 2]],
+    /// ]).unwrap();
+    ///
+    /// assert_eq!(
+    ///     polygon.boundary().to_string(),
+    ///     "MULTILINESTRING ((0 0, 0 3, 3 3, 3 0, 0 0), (1 1, 2 1, 2 2, 1 2, 1 1))",
+    /// );
+    /// ```
+    pub fn boundary(&self) -> Geometry {
+        Geometry::MultiLineString(MultiLineString::new(self.ring_line_strings()))
+    }
+
+    /// The area of this `Polygon`: the area enclosed by its exterior ring, less the area
+    /// of its holes.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::polygon::Polygon;
+    ///
+    /// let polygon = Polygon::new(vec![
+    ///     vec![[0, 0], [0, 3], [3, 3], [3, 0]],
+    ///     vec![[1, 1], [2, 1], [2, 2], [1, 2]],
+    /// ]).unwrap();
+    ///
+    /// assert_eq!(polygon.area(), 8.);
+    /// ```
+    pub fn area(&self) -> f64 {
+        let mut rings = self.iter().map(|ring| ring.signed_area().abs());
+        let exterior = rings.next().unwrap_or(0.);
+        exterior - rings.sum::<f64>()
+    }
+
+    /// The signed area of this `Polygon`: the sum of the signed areas of its rings, as
+    /// computed by [`PolygonRing::signed_area`].
+    ///
+    /// The sign follows the exterior ring, positive if it runs counter-clockwise and
+    /// negative if it runs clockwise. For a `Polygon` whose holes run the opposite way to
+    /// its exterior ring, the magnitude equals [`area`](Polygon::area).
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::polygon::Polygon;
+    ///
+    /// let square = Polygon::new(vec![vec![[0, 0], [1, 0], [1, 1], [0, 1]]]).unwrap();
+    /// let with_hole = Polygon::new(vec![
+    ///     vec![[0, 0], [3, 0], [3, 3], [0, 3]],
+    ///     vec![[1, 1], [1, 2], [2, 2], [2, 1]],
+    /// ]).unwrap();
+    ///
+    /// assert_eq!(square.signed_area(), 1.);
+    /// assert_eq!(square.reverse().signed_area(), -1.);
+    /// assert_eq!(with_hole.signed_area(), 8.);
+    /// assert_eq!(with_hole.reverse().signed_area(), -8.);
+    /// ```
+    pub fn signed_area(&self) -> f64 {
+        self.iter().map(PolygonRing::signed_area).sum()
+    }
+
+    /// Returns true if `point` lies in the interior of this `Polygon`.
+    ///
+    /// `Point`s on the boundary, including the boundary of a hole, are not contained.
+    /// Containment follows the even-odd rule: see
+    /// [`contains_point_winding`](Polygon::contains_point_winding) for a `Polygon` whose
+    /// rings overlap themselves.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::Point;
+    /// use auto_gis_with_rust::polygon::Polygon;
+    ///
+    /// let polygon = Polygon::new(vec![
+    ///     vec![[0, 0], [0, 3], [3, 3], [3, 0]],
+    ///     vec![[1, 1], [2, 1], [2, 2], [1, 2]],
+    /// ]).unwrap();
+    ///
+    /// assert!(polygon.contains_point(&Point::new(0.5, 0.5)));
+    /// assert!(!polygon.contains_point(&Point::new(1.5, 1.5)));
+    /// assert!(!polygon.contains_point(&Point::new(0, 1)));
+    /// assert!(!polygon.contains_point(&Point::new(4, 1)));
+    /// ```
+    pub fn contains_point(&self, point: &Point) -> bool {
+        self.contains_point_with(point, false)
+    }
+
+    /// Returns true if `point` lies inside this `Polygon`, counting `Point`s on its
+    /// boundary, including the boundary of a hole, as inside if `include_boundary` is true.
+    ///
+    /// [`contains_point`](Polygon::contains_point) is the same test with `include_boundary`
+    /// set to false.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::Point;
+    /// use auto_gis_with_rust::polygon::Polygon;
+    ///
+    /// let polygon = Polygon::new(vec![
+    ///     vec![[0, 0], [0, 3], [3, 3], [3, 0]],
+    ///     vec![[1, 1], [2, 1], [2, 2], [1, 2]],
+    /// ]).unwrap();
+    /// let on_edge = Point::new(0, 1);
+    /// let on_hole_edge = Point::new(1.5, 1);
+    ///
+    /// assert!(polygon.contains_point_with(&on_edge, true));
+    /// assert!(!polygon.contains_point_with(&on_edge, false));
+    /// assert!(polygon.contains_point_with(&on_hole_edge, true));
+    /// assert!(!polygon.contains_point_with(&on_hole_edge, false));
+    /// assert!(polygon.contains_point_with(&Point::new(0.5, 0.5), false));
+    /// assert!(!polygon.contains_point_with(&Point::new(1.5, 1.5), true));
+    /// ```
+    pub fn contains_point_with(&self, point: &Point, include_boundary: bool) -> bool {
+        if self.segments().any(|segment| segment.distance(point) == 0.) {
+            return include_boundary;
+        }
+        let crossings = self
+            .iter()
+            .flat_map(|ring| ring.windows(2))
+            .filter(|edge| {
+                let [[x_0, y_0], [x_1, y_1]] = [edge[0], edge[1]];
+                (y_0 > point.y()) != (y_1 > point.y())
+                    && point.x() < x_0 + (point.y() - y_0) * (x_1 - x_0) / (y_1 - y_0)
+            })
+            .count();
+        crossings % 2 == 1
+    }
+
+    /// Returns true if `point` lies in the interior of this `Polygon`, using winding numbers.
+    ///
+    /// [`contains_point`](Polygon::contains_point) casts a ray from `point` and counts the
+    /// edges it crosses, so a region that a self-overlapping ring wraps around twice counts
+    /// as outside. Here, `point` is instead inside a ring if the ring winds around it any
+    /// non-zero number of times, in either direction. `point` is contained if it is inside
+    /// the exterior ring and outside every hole, whatever the rings' orientations. For
+    /// rings that do not overlap themselves, both methods agree. `Point`s on the boundary
+    /// are not contained.
+    ///
+    /// # Examples:
+    ///
+    /// The center of a pentagram is wrapped twice by its ring.
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::Point;
+    /// use auto_gis_with_rust::polygon::Polygon;
+    ///
+    /// let pentagram = Polygon::new(vec![vec![[0, 3], [2, -3], [-3, 1], [3, 1], [-2, -3]]]).unwrap();
+    /// let center = Point::new(0, 0);
+    /// let point = Point::new(0, 2);
+    ///
+    /// assert!(pentagram.contains_point_winding(&center));
+    /// assert!(!pentagram.contains_point(&center));
+    /// assert!(pentagram.contains_point_winding(&point));
+    /// assert!(pentagram.contains_point(&point));
+    /// assert!(!pentagram.contains_point_winding(&Point::new(3, 3)));
+    /// ```
+    pub fn contains_point_winding(&self, point: &Point) -> bool {
+        if self.segments().any(|segment| segment.distance(point) == 0.) {
+            return false;
+        }
+        let winding_number = |ring: &PolygonRing| {
+            ring.windows(2)
+                .map(|edge| {
+                    let turn = helpers::cross_product(edge[0], edge[1], [point.x(), point.y()]);
+                    if edge[0][1] <= point.y() && edge[1][1] > point.y() && turn > 0. {
+                        1
+                    } else if edge[0][1] > point.y() && edge[1][1] <= point.y() && turn < 0. {
+                        -1
+                    } else {
+                        0
+                    }
+                })
+                .sum::<i64>()
+        };
+        let mut rings = self.iter().map(winding_number);
+        rings.next().unwrap_or(0) != 0 && rings.all(|winding| winding == 0)
+    }
+
+    /// The shortest distance between `point` and this `Polygon`: `0.0` if the `Point`
+    /// lies inside it, otherwise the distance to its nearest edge.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::Point;
+    /// use auto_gis_with_rust::polygon::Polygon;
+    ///
+    /// let polygon = Polygon::new(vec![vec![[0, 0], [0, 2], [2, 2], [2, 0]]]).unwrap();
+    ///
+    /// assert_eq!(polygon.distance(&Point::new(1, 1)), 0.);
+    /// assert_eq!(polygon.distance(&Point::new(5, 1)), 3.);
+    /// assert_eq!(polygon.distance(&Point::new(5, 6)), 5.);
+    /// ```
+    pub fn distance(&self, point: &Point) -> f64 {
+        if self.contains_point(point) {
+            return 0.;
+        }
+        self.segments()
+            .map(|segment| segment.distance(point))
+            .fold(f64::INFINITY, f64::min)
+    }
+
+    /// A `Point` guaranteed to lie in the interior of this `Polygon`, unlike its centroid,
+    /// which can fall in a hole or outside a concave shape.
+    ///
+    /// A horizontal line is swept through the middle of the `Polygon`'s vertical extent,
+    /// nudged so that it passes between vertices, and the midpoint of the widest interior
+    /// span along it is returned.
+    ///
+    /// # Examples:
+    ///
+    /// The center of a C-shaped `Polygon` lies in its opening, but the point on surface
+    /// lies in its spine.
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::Point;
+    /// use auto_gis_with_rust::polygon::Polygon;
+    ///
+    /// let polygon = Polygon::new(vec![vec![
+    ///     [0, 0], [3, 0], [3, 1], [1, 1], [1, 2], [3, 2], [3, 3], [0, 3],
+    /// ]]).unwrap();
+    ///
+    /// assert_eq!(polygon.point_on_surface(), Point::new(0.5, 1.5));
+    /// ```
+    pub fn point_on_surface(&self) -> Point {
+        let exterior = &self[0];
+        let min_y = exterior
+            .iter()
+            .map(|point| point[1])
+            .fold(f64::INFINITY, f64::min);
+        let max_y = exterior
+            .iter()
+            .map(|point| point[1])
+            .fold(f64::NEG_INFINITY, f64::max);
+        let center_y = (min_y + max_y) / 2.;
+
+        let vertex_ys = self
+            .iter()
+            .flat_map(|ring| ring.iter().map(|point| point[1]));
+        let below = vertex_ys
+            .clone()
+            .filter(|y| *y <= center_y)
+            .fold(min_y, f64::max);
+        let above = vertex_ys.filter(|y| *y > center_y).fold(max_y, f64::min);
+        let scan_y = (below + above) / 2.;
+
+        let mut crossings: Vec<f64> = self
+            .iter()
+            .flat_map(|ring| ring.windows(2))
+            .filter(|edge| (edge[0][1] > scan_y) != (edge[1][1] > scan_y))
+            .map(|edge| {
+                let [[x_0, y_0], [x_1, y_1]] = [edge[0], edge[1]];
+                x_0 + (scan_y - y_0) * (x_1 - x_0) / (y_1 - y_0)
+            })
+            .collect();
+        crossings.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        let widest = crossings
+            .chunks_exact(2)
+            .max_by(|a, b| (a[1] - a[0]).partial_cmp(&(b[1] - b[0])).unwrap());
+        match widest {
+            Some(span) => Point::new((span[0] + span[1]) / 2., scan_y),
+            None => Point::from(exterior[0]),
+        }
+    }
+
+    pub(crate) fn segments(&self) -> impl Iterator<Item = LineSegment> + '_ {
+        self.iter()
+            .flat_map(|ring| ring.windows(2))
+            .map(|edge| LineSegment::new([edge[0], edge[1]]))
+    }
+
+    pub(crate) fn ring_line_strings(&self) -> Vec<LineString> {
+        self.iter().map(PolygonRing::as_line_string).collect()
+    }
+
+    /// Returns true if this `Polygon` has the same rings as `other`, with every coordinate
+    /// no further than `tolerance` from the corresponding coordinate of `other`.
+    ///
+    /// If `normalize_rings` is true, two rings also match if they visit the same vertices
+    /// starting from a different one, or in the opposite direction.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::polygon::Polygon;
+    /// use auto_gis_with_rust::traits::EqualsExact;
+    ///
+    /// let square_1 = Polygon::new(vec![vec![[0., 0.], [1., 0.], [1., 1.], [0., 1.]]]).unwrap();
+    /// let square_2 = Polygon::new(vec![vec![[1., 1.], [0., 1.], [0., 0.], [1., 1e-9]]]).unwrap();
+    ///
+    /// assert!(!square_1.equals_exact(&square_2, 1e-6));
+    /// assert!(square_1.equals_exact_with(&square_2, 1e-6, true));
+    /// assert!(!square_1.equals_exact_with(&square_2, 1e-12, true));
+    /// ```
+    pub fn equals_exact_with(
+        &self,
+        other: &Polygon,
+        tolerance: f64,
+        normalize_rings: bool,
+    ) -> bool {
+        self.len() == other.len()
+            && self.iter().zip(other.iter()).all(|(ring, other)| {
+                if normalize_rings {
+                    helpers::rings_equal_normalized(ring, other, tolerance)
+                } else {
+                    ring.equals_exact(other, tolerance)
+                }
+            })
+    }
+
+    /// Return a copy of this `Polygon` with the vertex order of every ring reversed,
+    /// flipping its winding. Rings stay closed.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::polygon::Polygon;
+    ///
+    /// let polygon = Polygon::new(vec![vec![[0, 0], [1, 0], [1, 1]]]).unwrap();
+    /// let reversed = polygon.reverse();
+    ///
+    /// assert_eq!(reversed.to_string(), "POLYGON ((0 0, 1 1, 1 0, 0 0))");
+    /// assert_eq!(reversed.reverse(), polygon);
+    /// ```
+    pub fn reverse(&self) -> Polygon {
+        Polygon(
+            self.iter()
+                .map(|ring| PolygonRing(ring.iter().rev().copied().collect()))
+                .collect(),
+        )
+    }
+
+    /// A copy of this `Polygon` with spikes removed: vertices where the two edges meet
+    /// at an angle of less than `min_angle_deg` degrees.
+    ///
+    /// Spikes pointing both out of and into the `Polygon` are removed, as are any
+    /// repeated vertices left behind, until none remain. Rings stay closed and are never
+    /// reduced below three distinct vertices.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::polygon::Polygon;
+    ///
+    /// let square = Polygon::new(vec![vec![[0, 0], [2, 0], [2, 2], [0, 2]]]).unwrap();
+    /// let spiky = Polygon::new(vec![vec![[0, 0], [2, 0], [2, 2], [5, 5], [2, 2], [0, 2]]]).unwrap();
+    /// let needle = Polygon::new(vec![vec![
+    ///     [0., 0.], [2., 0.], [2., 1.], [8., 1.1], [2., 1.2], [2., 2.], [0., 2.],
+    /// ]]).unwrap();
+    ///
+    /// assert_eq!(spiky.remove_spikes(1.), square);
+    /// assert_eq!(
+    ///     needle.remove_spikes(5.).to_string(),
+    ///     "POLYGON ((0 0, 2 0, 2 1, 2 1.2, 2 2, 0 2, 0 0))"
+    /// );
+    /// assert_eq!(square.remove_spikes(45.), square);
+    /// ```
+    pub fn remove_spikes(&self, min_angle_deg: f64) -> Polygon {
+        Polygon(
+            self.iter()
+                .map(|ring| {
+                    let mut vertices = ring[..ring.len() - 1].to_vec();
+                    loop {
+                        vertices.dedup();
+                        while vertices.len() > 1 && vertices[0] == vertices[vertices.len() - 1] {
+                            vertices.pop();
+                        }
+                        let count = vertices.len();
+                        if count <= 3 {
+                            break;
+                        }
+                        let spike = (0..count).find(|&index| {
+                            let [x, y] = vertices[index];
+                            let [x_0, y_0] = vertices[(index + count - 1) % count];
+                            let [x_1, y_1] = vertices[(index + 1) % count];
+                            let [a, b] = [[x_0 - x, y_0 - y], [x_1 - x, y_1 - y]];
+                            let angle = (a[0] * b[1] - a[1] * b[0])
+                                .abs()
+                                .atan2(a[0] * b[0] + a[1] * b[1]);
+                            angle.to_degrees() < min_angle_deg
+                        });
+                        match spike {
+                            Some(index) => {
+                                vertices.remove(index);
+                            }
+                            None => break,
+                        }
+                    }
+                    vertices.push(vertices[0]);
+                    PolygonRing(vertices)
+                })
+                .collect(),
+        )
+    }
+
+    /// Split this `Polygon`, with longitudes in degrees as its x coordinates, into parts
+    /// that do not cross the antimeridian.
+    ///
+    /// As with [`LineString::split_at_antimeridian`], an edge whose longitudes differ by
+    /// more than 180° is taken to cross the antimeridian. The rings are unwrapped into
+    /// continuous longitudes, cut at the antimeridian, and the parts beyond it shifted
+    /// back by 360°. `Polygon`s enclosing a pole are not supported.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::polygon::Polygon;
+    ///
+    /// let polygon = Polygon::new(vec![vec![[170, 0], [-170, 0], [-170, 10], [170, 10]]]).unwrap();
+    /// let parts = polygon.split_at_antimeridian().unwrap();
+    ///
+    /// assert_eq!(
+    ///     parts.to_string(),
+    ///     "MULTIPOLYGON (((170 0, 180 0, 180 10, 170 10, 170 0)), \
+    ///     ((-180 0, -170 0, -170 10, -180 10, -180 0)))",
+    /// );
+    /// ```
+    pub fn split_at_antimeridian(&self) -> Result<MultiPolygon, GeometryError> {
+        let Some(first) = self.first().map(|ring| ring[0][0]) else {
+            return Ok(MultiPolygon::new(vec![]));
+        };
+        let unwrapped = Polygon(
+            self.iter()
+                .map(|ring| {
+                    let mut previous = first;
+                    let coordinates = ring
+                        .iter()
+                        .map(|&[x, y]| {
+                            let turns = ((x - previous) / 360.).round();
+                            previous = x - 360. * turns;
+                            [previous, y]
+                        })
+                        .collect();
+                    PolygonRing(coordinates)
+                })
+                .collect(),
+        );
+        let mut parts = Vec::new();
+        for shift in [-360., 0., 360.] {
+            let clip = Rect::new([shift - 180., -90.], [shift + 180., 90.]).to_polygon();
+            let clipped = unwrapped.intersection(&clip)?;
+            parts.extend(
+                clipped
+                    .into_polygons()
+                    .into_iter()
+                    .map(|part| part.map_coordinates(|[x, y]| [x - shift, y])),
+            );
+        }
+        Ok(MultiPolygon::new(parts))
+    }
+
+    /// The total number of vertices in the rings of this `Polygon`, counting the closing
+    /// vertex of each ring once.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::polygon::Polygon;
+    ///
+    /// let square_with_hole = Polygon::new(vec![
+    ///     vec![[0, 0], [4, 0], [4, 4], [0, 4]],
+    ///     vec![[1, 1], [1, 2], [2, 2], [2, 1]],
+    /// ]).unwrap();
+    ///
+    /// assert_eq!(square_with_hole.num_points(), 10);
+    /// ```
+    pub fn num_points(&self) -> usize {
+        self.iter().map(|ring| ring.len()).sum()
+    }
+
+    /// Returns true if this `Polygon` and `other` have rings through the same vertices,
+    /// regardless of which vertex each ring starts at, which way it runs, or the order of
+    /// the holes.
+    ///
+    /// Coordinates must match exactly; see [`equals_exact`](EqualsExact::equals_exact)
+    /// to allow for small numeric differences instead.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::polygon::Polygon;
+    ///
+    /// let square = Polygon::new(vec![vec![[0, 0], [2, 0], [2, 2], [0, 2]]]).unwrap();
+    /// let rotated = Polygon::new(vec![vec![[2, 2], [0, 2], [0, 0], [2, 0]]]).unwrap();
+    /// let reversed = Polygon::new(vec![vec![[2, 0], [0, 0], [0, 2], [2, 2]]]).unwrap();
+    /// let bowtie = Polygon::new(vec![vec![[0, 0], [2, 2], [2, 0], [0, 2]]]).unwrap();
+    ///
+    /// assert_ne!(square, rotated);
+    /// assert!(square.geometrically_equal(&rotated));
+    /// assert!(square.geometrically_equal(&reversed));
+    /// assert!(!square.geometrically_equal(&bowtie));
+    /// ```
+    ///
+    /// Holes may be listed in any order, but each must match a different hole.
+    ///
+    /// ```
+    /// use auto_gis_with_rust::polygon::Polygon;
+    ///
+    /// let shell = vec![[0, 0], [9, 0], [9, 9], [0, 9]];
+    /// let left = vec![[1, 1], [1, 2], [2, 2], [2, 1]];
+    /// let right = vec![[6, 6], [6, 7], [7, 7], [7, 6]];
+    ///
+    /// let polygon = Polygon::new(vec![shell.clone(), left.clone(), right.clone()]).unwrap();
+    /// let swapped = Polygon::new(vec![shell.clone(), right.clone(), left.clone()]).unwrap();
+    /// let doubled = Polygon::new(vec![shell, left.clone(), left]).unwrap();
+    ///
+    /// assert!(polygon.geometrically_equal(&swapped));
+    /// assert!(!polygon.geometrically_equal(&doubled));
+    /// assert!(!doubled.geometrically_equal(&polygon));
+    /// ```
+    pub fn geometrically_equal(&self, other: &Polygon) -> bool {
+        if self.len() != other.len() {
+            return false;
+        }
+        if self.is_empty() {
+            return true;
+        }
+        let mut unmatched: Vec<&PolygonRing> = other[1..].iter().collect();
+        helpers::rings_equal_normalized(&self[0], &other[0], 0.)
+            && self[1..].iter().all(|hole| {
+                match unmatched
+                    .iter()
+                    .position(|other| helpers::rings_equal_normalized(hole, other, 0.))
+                {
+                    Some(index) => {
+                        unmatched.swap_remove(index);
+                        true
+                    }
+                    None => false,
+                }
+            })
+    }
+
+    /// A copy of this `Polygon` with vertices added along every edge longer than
+    /// `max_segment_length`, as [`LineString::densify`] does for each ring.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::polygon::Polygon;
+    ///
+    /// let square = Polygon::new(vec![vec![[0, 0], [2, 0], [2, 2], [0, 2]]]).unwrap();
+    /// let densified = square.densify(1.);
+    ///
+    /// assert_eq!(densified.num_points(), 9);
+    /// assert_eq!(densified.area(), square.area());
+    /// ```
+    pub fn densify(&self, max_segment_length: f64) -> Polygon {
+        Polygon(
+            self.iter()
+                .map(|ring| PolygonRing(densify_coordinates(ring, max_segment_length)))
+                .collect(),
+        )
+    }
 }
 
 implement_deref!(Polygon, Vec<PolygonRing>);
@@ -91,7 +829,179 @@ impl fmt::Display for Polygon {
         write!(f, "POLYGON ({})", rings)
     }
 }
-#[derive(Debug, PartialEq, PartialOrd)]
+
+impl fmt::Debug for Polygon {
+    /// Summarize this `Polygon` as its numbers of rings and `Point`s and its envelope, or
+    /// list every coordinate in the alternate `{:#?}` form.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::polygon::Polygon;
+    ///
+    /// let polygon = Polygon::new(vec![
+    ///     vec![[0, 0], [0, 3], [3, 3], [3, 0]],
+    ///     vec![[1, 1], [2, 1], [2, 2], [1, 2]],
+    /// ]).unwrap();
+    ///
+    /// assert_eq!(
+    ///     format!("{:?}", polygon),
+    ///     "Polygon { rings: 2, points: 10, bbox: [[0.0, 0.0], [3.0, 3.0]] }",
+    /// );
+    /// assert!(format!("{:#?}", polygon).starts_with("Polygon(\n    [\n        PolygonRing("));
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        helpers::debug_geometry(
+            f,
+            "Polygon",
+            &[
+                ("rings", self.len()),
+                ("points", self.iter().map(|ring| ring.len()).sum()),
+            ],
+            self.envelope(),
+            &self.0,
+        )
+    }
+}
+
+impl traits::Geometry for Polygon {
+    /// Compute the geometric center of a geometry.
+    ///
+    /// For a `Polygon`, this is its center of mass, with holes removed from the area.
+    /// A `Polygon` with no area falls back to the mean of its exterior ring's vertices.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::Point;
+    /// use auto_gis_with_rust::polygon::Polygon;
+    /// use auto_gis_with_rust::traits::Geometry;
+    ///
+    /// let polygon = Polygon::new(vec![
+    ///     vec![[0, 0], [4, 0], [4, 2], [0, 2]],
+    ///     vec![[2, 0], [4, 0], [4, 2], [2, 2]],
+    /// ]).unwrap();
+    ///
+    /// assert_eq!(polygon.centroid(), Point::new(1, 1));
+    /// ```
+    fn centroid(&self) -> Point {
+        let mut area = 0.;
+        let mut moment = [0., 0.];
+        for (index, ring) in self.iter().enumerate() {
+            let (ring_area, ring_moment) = ring_moments(ring);
+            let sign = if index == 0 { 1. } else { -1. } * ring_area.signum();
+            area += sign * ring_area;
+            moment = [
+                moment[0] + sign * ring_moment[0],
+                moment[1] + sign * ring_moment[1],
+            ];
+        }
+        if area != 0. {
+            return Point::new(moment[0] / area, moment[1] / area);
+        }
+        let vertices = self
+            .first()
+            .map_or(&[][..], |exterior| &exterior[..exterior.len() - 1]);
+        let count = vertices.len() as f64;
+        let [sum_x, sum_y] = vertices
+            .iter()
+            .fold([0., 0.], |[x, y], vertex| [x + vertex[0], y + vertex[1]]);
+        Point::new(sum_x / count, sum_y / count)
+    }
+
+    /// A `Polygon` is always simple: a ring crossing itself makes the `Polygon` invalid,
+    /// not non-simple.
+    fn is_simple(&self) -> bool {
+        true
+    }
+}
+
+/// The signed area of the closed ring `coordinates`, and its first moments of area about
+/// the y- and x-axes, so that the ring's centroid is `moment / area`.
+fn ring_moments(coordinates: &[[f64; 2]]) -> (f64, [f64; 2]) {
+    coordinates
+        .windows(2)
+        .fold((0., [0., 0.]), |(area, [moment_x, moment_y]), pair| {
+            let [[x_0, y_0], [x_1, y_1]] = [pair[0], pair[1]];
+            let cross = x_0 * y_1 - x_1 * y_0;
+            (
+                area + cross / 2.,
+                [
+                    moment_x + (x_0 + x_1) * cross / 6.,
+                    moment_y + (y_0 + y_1) * cross / 6.,
+                ],
+            )
+        })
+}
+
+impl ConvexHull for Polygon {
+    /// The convex hull of the vertices of the rings of this `Polygon`.
+    fn convex_hull(&self) -> Result<Polygon, GeometryError> {
+        let coordinates: Vec<[f64; 2]> =
+            self.iter().flat_map(|ring| ring.iter().copied()).collect();
+        helpers::convex_hull_polygon(&coordinates)
+    }
+}
+
+impl Envelope for Polygon {
+    /// Holes lie inside the exterior ring, so only the exterior ring is considered.
+    fn envelope(&self) -> Option<Rect> {
+        self.first().and_then(|exterior| exterior.envelope())
+    }
+}
+
+impl Dimensions for Polygon {
+    /// A `Polygon` is 2-dimensional.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::polygon::Polygon;
+    /// use auto_gis_with_rust::traits::Dimensions;
+    ///
+    /// let polygon = Polygon::new(vec![vec![[0, 0], [0, 1], [1, 1]]]).unwrap();
+    ///
+    /// assert_eq!(polygon.dimension(), 2);
+    /// ```
+    fn dimension(&self) -> u8 {
+        2
+    }
+}
+
+impl GeometryType for Polygon {
+    fn geometry_type(&self) -> &'static str {
+        "Polygon"
+    }
+}
+
+impl EqualsExact for Polygon {
+    /// Compares rings vertex by vertex. See [`Polygon::equals_exact_with`] to also accept
+    /// rings that start at a different vertex or run in the opposite direction.
+    fn equals_exact(&self, other: &Self, tolerance: f64) -> bool {
+        self.equals_exact_with(other, tolerance, false)
+    }
+}
+
+impl MapCoordinates for Polygon {
+    fn map_coordinates<F: Fn([f64; 2]) -> [f64; 2]>(&self, function: F) -> Self {
+        Polygon(
+            self.iter()
+                .map(|ring| ring.map_coordinates(&function))
+                .collect(),
+        )
+    }
+
+    #[cfg(feature = "parallel")]
+    fn par_map_coordinates<F: Fn([f64; 2]) -> [f64; 2] + Sync + Send>(&self, function: F) -> Self {
+        Polygon(
+            self.par_iter()
+                .map(|ring| ring.par_map_coordinates(&function))
+                .collect(),
+        )
+    }
+}
+
+#[derive(Default, PartialEq, PartialOrd, Clone)]
 pub struct MultiPolygon(Vec<Polygon>);
 
 impl MultiPolygon {
@@ -112,6 +1022,144 @@ impl MultiPolygon {
     pub fn new(polygons: Vec<Polygon>) -> Self {
         MultiPolygon(polygons)
     }
+
+    /// The total area of the `Polygon`s in this `MultiPolygon`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use auto_gis_with_rust::polygon::MultiPolygon;
+    ///
+    /// let multi_polygon = MultiPolygon::try_from(vec![
+    ///     vec![vec![[0, 0], [1, 0], [1, 1], [0, 1]]],
+    ///     vec![vec![[2, 0], [4, 0], [4, 2], [2, 2]]],
+    /// ]).unwrap();
+    ///
+    /// assert_eq!(multi_polygon.area(), 5.);
+    /// ```
+    pub fn area(&self) -> f64 {
+        self.iter().map(|polygon| polygon.area()).sum()
+    }
+
+    /// The boundary of this `MultiPolygon`: a `MultiLineString` made of the rings of all
+    /// its `Polygon`s.
+    pub fn boundary(&self) -> Geometry {
+        let line_strings: Vec<LineString> = self
+            .iter()
+            .flat_map(|polygon| polygon.ring_line_strings())
+            .collect();
+        Geometry::MultiLineString(MultiLineString::new(line_strings))
+    }
+
+    /// The mean of this `MultiPolygon`'s `Polygon` centroids weighted by their areas, or
+    /// `None` if it is empty.
+    ///
+    /// If none of the `Polygon`s have any area, their centroids are weighted equally.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::Point;
+    /// use auto_gis_with_rust::polygon::{MultiPolygon, Polygon};
+    ///
+    /// let polygon = Polygon::new(vec![vec![[0, 0], [2, 0], [2, 2], [0, 2]]]).unwrap();
+    ///
+    /// assert_eq!(MultiPolygon::new(vec![polygon]).checked_centroid(), Some(Point::new(1, 1)));
+    /// assert_eq!(MultiPolygon::new(vec![]).checked_centroid(), None);
+    /// ```
+    pub fn checked_centroid(&self) -> Option<Point> {
+        if self.is_empty() {
+            return None;
+        }
+        let total_area = self.area();
+        let [sum_x, sum_y] = self.iter().fold([0., 0.], |[x, y], polygon| {
+            let weight = if total_area == 0. { 1. } else { polygon.area() };
+            let centroid = traits::Geometry::centroid(polygon);
+            [x + weight * centroid.x(), y + weight * centroid.y()]
+        });
+        let total_weight = if total_area == 0. {
+            self.len() as f64
+        } else {
+            total_area
+        };
+        Some(Point::new(sum_x / total_weight, sum_y / total_weight))
+    }
+
+    /// The `Polygon`s that make up this `MultiPolygon`.
+    pub fn polygons(&self) -> &[Polygon] {
+        &self.0
+    }
+
+    /// Consume this `MultiPolygon`, returning its owned `Polygon`s.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::polygon::{MultiPolygon, Polygon};
+    ///
+    /// let first = Polygon::new(vec![vec![[0, 0], [1, 0], [1, 1]]]).unwrap();
+    /// let second = Polygon::new(vec![vec![[2, 0], [3, 0], [3, 1]]]).unwrap();
+    /// let multi_polygon = MultiPolygon::new(vec![first.clone(), second.clone()]);
+    ///
+    /// assert_eq!(multi_polygon.polygons(), &[first.clone(), second.clone()]);
+    /// assert_eq!(multi_polygon.into_polygons(), vec![first, second]);
+    /// ```
+    pub fn into_polygons(self) -> Vec<Polygon> {
+        self.0
+    }
+
+    /// The total number of vertices in the rings of every `Polygon` in this
+    /// `MultiPolygon`, counting the closing vertex of each ring once.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use auto_gis_with_rust::polygon::MultiPolygon;
+    ///
+    /// let multi_polygon = MultiPolygon::try_from(vec![
+    ///     vec![vec![[0, 0], [4, 0], [4, 4], [0, 4]], vec![[1, 1], [1, 2], [2, 2], [2, 1]]],
+    ///     vec![vec![[5, 0], [6, 0], [6, 1]]],
+    /// ]).unwrap();
+    ///
+    /// assert_eq!(multi_polygon.num_points(), 14);
+    /// ```
+    pub fn num_points(&self) -> usize {
+        self.iter().map(Polygon::num_points).sum()
+    }
+
+    /// Returns true if `point` lies in the interior of any `Polygon` in this
+    /// `MultiPolygon`, as found by [`Polygon::contains_point`].
+    ///
+    /// `Polygon`s whose envelope does not cover `point` are skipped without testing their
+    /// rings, and the search stops at the first `Polygon` containing it.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use auto_gis_with_rust::point::Point;
+    /// use auto_gis_with_rust::polygon::MultiPolygon;
+    ///
+    /// let islands = MultiPolygon::try_from(vec![
+    ///     vec![vec![[0, 0], [2, 0], [2, 2], [0, 2]]],
+    ///     vec![vec![[5, 0], [7, 0], [7, 2], [5, 2]]],
+    /// ]).unwrap();
+    ///
+    /// assert!(islands.contains_point(&Point::new(1, 1)));
+    /// assert!(islands.contains_point(&Point::new(6, 1)));
+    /// assert!(!islands.contains_point(&Point::new(3.5, 1)));
+    /// ```
+    pub fn contains_point(&self, point: &Point) -> bool {
+        let location = Rect::new([point.x(), point.y()], [point.x(), point.y()]);
+        self.iter().any(|polygon| {
+            polygon
+                .envelope()
+                .is_some_and(|envelope| envelope.contains_rect(&location))
+                && polygon.contains_point(point)
+        })
+    }
 }
 
 implement_deref!(MultiPolygon, Vec<Polygon>);
@@ -135,6 +1183,41 @@ impl fmt::Display for MultiPolygon {
     }
 }
 
+impl fmt::Debug for MultiPolygon {
+    /// Summarize this `MultiPolygon` as its numbers of `Polygon`s, rings and `Point`s and
+    /// its envelope, or list every coordinate in the alternate `{:#?}` form.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::polygon::MultiPolygon;
+    ///
+    /// assert_eq!(
+    ///     format!("{:?}", MultiPolygon::new(vec![])),
+    ///     "MultiPolygon { polygons: 0, rings: 0, points: 0, bbox: None }",
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        helpers::debug_geometry(
+            f,
+            "MultiPolygon",
+            &[
+                ("polygons", self.len()),
+                ("rings", self.iter().map(|polygon| polygon.len()).sum()),
+                (
+                    "points",
+                    self.iter()
+                        .flat_map(|polygon| polygon.iter())
+                        .map(|ring| ring.len())
+                        .sum(),
+                ),
+            ],
+            self.envelope(),
+            &self.0,
+        )
+    }
+}
+
 impl<T: NumCast> TryFrom<Vec<Vec<Vec<[T; 2]>>>> for MultiPolygon {
     type Error = GeometryError;
 
@@ -190,3 +1273,176 @@ impl<T: NumCast> TryFrom<Vec<Vec<Vec<[T; 2]>>>> for MultiPolygon {
         Ok(MultiPolygon::new(polygons?))
     }
 }
+
+impl<I: SliceIndex<[Polygon]>> Index<I> for MultiPolygon {
+    type Output = I::Output;
+
+    /// The `Polygon` at `index`, or the slice of `Polygon`s in a range of indices, panicking if
+    /// `index` is out of bounds.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::polygon::{MultiPolygon, Polygon};
+    ///
+    /// let polygon = Polygon::new(vec![vec![[0, 0], [1, 0], [1, 1]]]).unwrap();
+    /// let multi_polygon = MultiPolygon::new(vec![polygon.clone()]);
+    ///
+    /// assert_eq!(multi_polygon[0], polygon);
+    /// ```
+    fn index(&self, index: I) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl Extend<Polygon> for MultiPolygon {
+    /// Append the `Polygon`s yielded by `iter` to this `MultiPolygon`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::polygon::{MultiPolygon, Polygon};
+    ///
+    /// let mut multi_polygon = MultiPolygon::default();
+    /// multi_polygon.extend(vec![Polygon::new(vec![vec![[0, 0], [1, 0], [1, 1]]]).unwrap()]);
+    ///
+    /// assert_eq!(multi_polygon.to_string(), "MULTIPOLYGON (((0 0, 1 0, 1 1, 0 0)))");
+    /// ```
+    fn extend<I: IntoIterator<Item = Polygon>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
+impl IntoIterator for MultiPolygon {
+    type Item = Polygon;
+    type IntoIter = std::vec::IntoIter<Polygon>;
+
+    /// Consume this `MultiPolygon`, yielding its owned `Polygon`s.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use auto_gis_with_rust::polygon::{MultiPolygon, Polygon};
+    ///
+    /// let multi_polygon = MultiPolygon::try_from(vec![
+    ///     vec![vec![[0, 0], [0, 1], [1, 1]]],
+    ///     vec![vec![[1, 1], [1, 2], [2, 2]]],
+    /// ]).unwrap();
+    /// let polygons: Vec<Polygon> = multi_polygon.into_iter().collect();
+    ///
+    /// assert_eq!(polygons[0].to_string(), "POLYGON ((0 0, 0 1, 1 1, 0 0))");
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a MultiPolygon {
+    type Item = &'a Polygon;
+    type IntoIter = std::slice::Iter<'a, Polygon>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl traits::Geometry for MultiPolygon {
+    /// Compute the geometric center of a geometry.
+    ///
+    /// For a `MultiPolygon`, this is the mean of its `Polygon`s' centroids weighted by
+    /// their areas. See [`MultiPolygon::checked_centroid`] for a version that doesn't
+    /// panic.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this `MultiPolygon` is empty.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::Point;
+    /// use auto_gis_with_rust::polygon::{MultiPolygon, Polygon};
+    /// use auto_gis_with_rust::traits::Geometry;
+    ///
+    /// let large = Polygon::new(vec![vec![[0, 0], [3, 0], [3, 3], [0, 3]]]).unwrap();
+    /// let small = Polygon::new(vec![vec![[10, 0], [11, 0], [11, 1], [10, 1]]]).unwrap();
+    /// let multi_polygon = MultiPolygon::new(vec![large, small]);
+    ///
+    /// assert_eq!(multi_polygon.centroid(), Point::new(2.4, 1.4));
+    /// ```
+    fn centroid(&self) -> Point {
+        self.checked_centroid()
+            .expect("an empty MultiPolygon has no centroid")
+    }
+
+    /// A `MultiPolygon` is always simple, see the `is_simple` implementation for
+    /// `Polygon`.
+    fn is_simple(&self) -> bool {
+        true
+    }
+}
+
+impl GeometryType for MultiPolygon {
+    fn geometry_type(&self) -> &'static str {
+        "MultiPolygon"
+    }
+}
+
+impl EqualsExact for MultiPolygon {
+    fn equals_exact(&self, other: &Self, tolerance: f64) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(polygon, other)| polygon.equals_exact(other, tolerance))
+    }
+}
+
+impl MapCoordinates for MultiPolygon {
+    fn map_coordinates<F: Fn([f64; 2]) -> [f64; 2]>(&self, function: F) -> Self {
+        MultiPolygon(
+            self.iter()
+                .map(|polygon| polygon.map_coordinates(&function))
+                .collect(),
+        )
+    }
+
+    #[cfg(feature = "parallel")]
+    fn par_map_coordinates<F: Fn([f64; 2]) -> [f64; 2] + Sync + Send>(&self, function: F) -> Self {
+        MultiPolygon(
+            self.par_iter()
+                .map(|polygon| polygon.par_map_coordinates(&function))
+                .collect(),
+        )
+    }
+}
+
+impl ConvexHull for MultiPolygon {
+    /// The convex hull of the vertices of the rings of every `Polygon` in this
+    /// `MultiPolygon`.
+    fn convex_hull(&self) -> Result<Polygon, GeometryError> {
+        let coordinates: Vec<[f64; 2]> = self
+            .iter()
+            .flat_map(|polygon| polygon.iter().flat_map(|ring| ring.iter().copied()))
+            .collect();
+        helpers::convex_hull_polygon(&coordinates)
+    }
+}
+
+impl Envelope for MultiPolygon {
+    fn envelope(&self) -> Option<Rect> {
+        Rect::from_coordinates(
+            self.iter()
+                .flat_map(|polygon| polygon.first())
+                .flat_map(|exterior| exterior.iter().copied()),
+        )
+    }
+}
+
+impl Dimensions for MultiPolygon {
+    /// A `MultiPolygon` is 2-dimensional.
+    fn dimension(&self) -> u8 {
+        2
+    }
+}
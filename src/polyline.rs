@@ -0,0 +1,119 @@
+use crate::error::GeometryError;
+use crate::line_string::LineString;
+
+/// Encode a `LineString` with Google's encoded polyline algorithm.
+///
+/// Coordinates are rounded to `precision` decimal places: 5 for Google's own APIs, 6 for
+/// e.g. OSRM and Valhalla. Following the format, each `Point`'s y-coordinate (latitude)
+/// is written before its x-coordinate (longitude).
+///
+/// # Examples:
+///
+/// ```
+/// use auto_gis_with_rust::line_string::LineString;
+/// use auto_gis_with_rust::polyline::encode_polyline;
+///
+/// let line_string = LineString::new(vec![
+///     [-120.2, 38.5],
+///     [-120.95, 40.7],
+///     [-126.453, 43.252],
+/// ]).unwrap();
+///
+/// assert_eq!(encode_polyline(&line_string, 5), "_p~iF~ps|U_ulLnnqC_mqNvxq`@");
+/// ```
+pub fn encode_polyline(line: &LineString, precision: u32) -> String {
+    let factor = 10f64.powi(precision as i32);
+    let mut encoded = String::new();
+    let mut previous = [0i64; 2];
+    for [x, y] in line.iter() {
+        let current = [(y * factor).round() as i64, (x * factor).round() as i64];
+        for (value, previous_value) in current.iter().zip(previous) {
+            encode_value(value - previous_value, &mut encoded);
+        }
+        previous = current;
+    }
+    encoded
+}
+
+/// Decode a `LineString` encoded with Google's encoded polyline algorithm at `precision`
+/// decimal places.
+///
+/// Returns `GeometryError::InvalidPolyline` if the string is malformed, or
+/// `GeometryError::TooFewCoords` if it holds fewer than two `Point`s.
+///
+/// # Examples:
+///
+/// ```
+/// use auto_gis_with_rust::polyline::decode_polyline;
+///
+/// let line_string = decode_polyline("_p~iF~ps|U_ulLnnqC_mqNvxq`@", 5).unwrap();
+/// let expected = [[-120.2, 38.5], [-120.95, 40.7], [-126.453, 43.252]];
+///
+/// for (coordinate, expected) in line_string.iter().zip(expected) {
+///     assert!((coordinate[0] - expected[0]).abs() < 1e-9);
+///     assert!((coordinate[1] - expected[1]).abs() < 1e-9);
+/// }
+/// ```
+///
+/// Encoding at precision 6 round-trips too.
+///
+/// ```
+/// use auto_gis_with_rust::line_string::LineString;
+/// use auto_gis_with_rust::polyline::{decode_polyline, encode_polyline};
+///
+/// let line_string = LineString::new(vec![[13.388798, 52.517033], [13.397631, 52.529432]]).unwrap();
+/// let decoded = decode_polyline(&encode_polyline(&line_string, 6), 6).unwrap();
+///
+/// for (coordinate, expected) in decoded.iter().zip(line_string.iter()) {
+///     assert!((coordinate[0] - expected[0]).abs() < 1e-9);
+///     assert!((coordinate[1] - expected[1]).abs() < 1e-9);
+/// }
+///
+/// assert!(decode_polyline("_p~iF~ps|", 6).is_err());
+/// ```
+pub fn decode_polyline(s: &str, precision: u32) -> Result<LineString, GeometryError> {
+    let factor = 10f64.powi(precision as i32);
+    let mut bytes = s.bytes().peekable();
+    let mut coordinates = Vec::new();
+    let mut current = [0i64; 2];
+    while bytes.peek().is_some() {
+        for value in current.iter_mut() {
+            *value += decode_value(&mut bytes, s)?;
+        }
+        coordinates.push([current[1] as f64 / factor, current[0] as f64 / factor]);
+    }
+    LineString::new(coordinates)
+}
+
+fn encode_value(value: i64, encoded: &mut String) {
+    let mut value = if value < 0 { !(value << 1) } else { value << 1 };
+    while value >= 0x20 {
+        encoded.push((((value & 0x1f) | 0x20) as u8 + 63) as char);
+        value >>= 5;
+    }
+    encoded.push((value as u8 + 63) as char);
+}
+
+fn decode_value(bytes: &mut impl Iterator<Item = u8>, s: &str) -> Result<i64, GeometryError> {
+    let mut result: i64 = 0;
+    let mut shift = 0;
+    loop {
+        let byte = bytes
+            .next()
+            .ok_or_else(|| GeometryError::InvalidPolyline(s.to_string()))?;
+        if !(63..127).contains(&byte) || shift > 60 {
+            return Err(GeometryError::InvalidPolyline(s.to_string()));
+        }
+        let chunk = (byte - 63) as i64;
+        result |= (chunk & 0x1f) << shift;
+        shift += 5;
+        if chunk < 0x20 {
+            break;
+        }
+    }
+    Ok(if result & 1 == 1 {
+        !(result >> 1)
+    } else {
+        result >> 1
+    })
+}
@@ -0,0 +1,248 @@
+use crate::line_string::MultiLineString;
+use crate::point::Point;
+use crate::polygon::Polygon;
+use crate::topology;
+use crate::traits::Envelope;
+
+/// Where a `Point` lies relative to a `Polygon`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Location {
+    Interior,
+    Boundary,
+    Exterior,
+}
+
+impl Polygon {
+    /// The DE-9IM matrix describing how this `Polygon` and `other` intersect, as a
+    /// 9-character string.
+    ///
+    /// The characters are the dimensions of the intersections of the interior, boundary
+    /// and exterior of `self` (rows) with those of `other` (columns), in row-major order:
+    /// `2` for an area, `1` for a line, `0` for a point and `F` for no intersection.
+    ///
+    /// The boundary cells are computed exactly from the noded rings of both `Polygon`s.
+    /// The interior-interior, interior-exterior and exterior-interior cells are
+    /// approximated by sampling a point just either side of every noded edge, so faces of
+    /// the overlay narrower than about a millionth of the combined extent can be missed.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::polygon::Polygon;
+    ///
+    /// let square_1 = Polygon::new(vec![vec![[0, 0], [2, 0], [2, 2], [0, 2]]]).unwrap();
+    /// let square_2 = Polygon::new(vec![vec![[1, 1], [3, 1], [3, 3], [1, 3]]]).unwrap();
+    /// let square_3 = Polygon::new(vec![vec![[5, 5], [6, 5], [6, 6], [5, 6]]]).unwrap();
+    ///
+    /// assert_eq!(square_1.relate(&square_2), "212101212");
+    /// assert_eq!(square_1.relate(&square_1), "2FFF1FFF2");
+    /// assert_eq!(square_1.relate(&square_3), "FF2FF1212");
+    /// ```
+    pub fn relate(&self, other: &Polygon) -> String {
+        let mut matrix = [[None; 3]; 3];
+        matrix[2][2] = Some(2);
+
+        let tolerance = 1e-9 * extent(self, other);
+        let offset = 1e-6 * extent(self, other);
+
+        let rings: Vec<_> = self
+            .ring_line_strings()
+            .into_iter()
+            .chain(other.ring_line_strings())
+            .collect();
+        let edges = topology::node(&MultiLineString::new(rings));
+        for edge in edges.iter() {
+            for pair in edge.windows(2) {
+                let [[x_0, y_0], [x_1, y_1]] = [pair[0], pair[1]];
+                let middle = Point::new((x_0 + x_1) / 2., (y_0 + y_1) / 2.);
+                let row = self.locate(&middle, tolerance) as usize;
+                let column = other.locate(&middle, tolerance) as usize;
+                raise(&mut matrix[row][column], 1);
+
+                let length = (x_1 - x_0).hypot(y_1 - y_0);
+                let normal = [-(y_1 - y_0) / length, (x_1 - x_0) / length];
+                for side in [-1., 1.] {
+                    let sample = Point::new(
+                        middle.x() + side * offset * normal[0],
+                        middle.y() + side * offset * normal[1],
+                    );
+                    let row = self.locate(&sample, tolerance) as usize;
+                    let column = other.locate(&sample, tolerance) as usize;
+                    raise(&mut matrix[row][column], 2);
+                }
+            }
+        }
+
+        let boundaries_touch = self.segments().any(|segment| {
+            other
+                .segments()
+                .any(|other| !segment.intersection_coordinates(&other).is_empty())
+        });
+        if boundaries_touch {
+            raise(&mut matrix[1][1], 0);
+        }
+
+        matrix
+            .iter()
+            .flatten()
+            .map(|cell| match cell {
+                Some(dimension) => char::from(b'0' + dimension),
+                None => 'F',
+            })
+            .collect()
+    }
+
+    /// Returns true if `other` lies wholly in the interior of this `Polygon`, without
+    /// touching its boundary.
+    ///
+    /// This is the DE-9IM pattern `T**FF*FF*`, which GEOS calls "contains properly". It is
+    /// stricter than [`covers`](Polygon::covers), which also accepts a `Polygon` that
+    /// touches or shares part of this `Polygon`'s boundary from the inside.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::polygon::Polygon;
+    ///
+    /// let large = Polygon::new(vec![vec![[0, 0], [4, 0], [4, 4], [0, 4]]]).unwrap();
+    /// let inner = Polygon::new(vec![vec![[1, 1], [2, 1], [2, 2], [1, 2]]]).unwrap();
+    /// let edge = Polygon::new(vec![vec![[0, 0], [1, 0], [1, 1], [0, 1]]]).unwrap();
+    ///
+    /// assert!(large.contains(&inner));
+    /// assert!(!large.contains(&edge));
+    /// ```
+    ///
+    /// A `Polygon` can only contain another if its envelope contains the other's, so
+    /// `Polygon`s whose envelopes do not nest are rejected without comparing their edges.
+    ///
+    /// ```
+    /// use auto_gis_with_rust::polygon::Polygon;
+    /// use auto_gis_with_rust::traits::Envelope;
+    ///
+    /// let large = Polygon::new(vec![vec![[0, 0], [4, 0], [4, 4], [0, 4]]]).unwrap();
+    /// let nested = Polygon::new(vec![vec![[1, 1], [3, 1], [3, 3], [1, 3]]]).unwrap();
+    /// let touching = Polygon::new(vec![vec![[0, 0], [2, 0], [2, 2], [0, 2]]]).unwrap();
+    /// let overhanging = Polygon::new(vec![vec![[3, 1], [5, 1], [5, 3], [3, 3]]]).unwrap();
+    ///
+    /// assert!(large.contains(&nested) && large.covers(&nested));
+    /// assert!(!large.contains(&touching) && large.covers(&touching));
+    /// assert!(!large.envelope().unwrap().contains_rect(&overhanging.envelope().unwrap()));
+    /// assert!(!large.contains(&overhanging) && !large.covers(&overhanging));
+    /// ```
+    pub fn contains(&self, other: &Polygon) -> bool {
+        if !self.envelope_contains(other) {
+            return false;
+        }
+        contains_matrix(&self.relate(other))
+    }
+
+    /// Returns true if no part of `other` lies outside this `Polygon`.
+    ///
+    /// Unlike [`contains`](Polygon::contains), `other` may touch or share part of this
+    /// `Polygon`'s boundary, so a `Polygon` covers itself. This is the DE-9IM pattern
+    /// `T*****FF*`.
+    ///
+    /// # Examples:
+    ///
+    /// A small square sharing an edge with a larger one is covered by it, but not
+    /// contained in it.
+    ///
+    /// ```
+    /// use auto_gis_with_rust::polygon::Polygon;
+    ///
+    /// let large = Polygon::new(vec![vec![[0, 0], [2, 0], [2, 2], [0, 2]]]).unwrap();
+    /// let small = Polygon::new(vec![vec![[0, 0], [1, 0], [1, 1], [0, 1]]]).unwrap();
+    ///
+    /// assert!(large.covers(&small));
+    /// assert!(!large.contains(&small));
+    /// assert!(!small.covers(&large));
+    /// ```
+    pub fn covers(&self, other: &Polygon) -> bool {
+        if !self.envelope_contains(other) {
+            return false;
+        }
+        covers_matrix(&self.relate(other))
+    }
+
+    /// Returns true if no part of this `Polygon` lies outside `other`, i.e. if `other`
+    /// covers this `Polygon`. Shared boundaries are allowed.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::polygon::Polygon;
+    ///
+    /// let large = Polygon::new(vec![vec![[0, 0], [2, 0], [2, 2], [0, 2]]]).unwrap();
+    /// let small = Polygon::new(vec![vec![[0, 0], [1, 0], [1, 1], [0, 1]]]).unwrap();
+    ///
+    /// assert!(small.covered_by(&large));
+    /// assert!(!large.covered_by(&small));
+    /// ```
+    pub fn covered_by(&self, other: &Polygon) -> bool {
+        other.covers(self)
+    }
+
+    /// Whether the envelope of `other` lies within the envelope of this `Polygon`, which
+    /// it must for this `Polygon` to cover `other`. This is far cheaper than `relate`, so
+    /// it is checked first to rule out most pairs of `Polygon`s. Empty `Polygon`s are
+    /// left for `relate` to decide.
+    fn envelope_contains(&self, other: &Polygon) -> bool {
+        match (self.envelope(), other.envelope()) {
+            (Some(envelope), Some(other_envelope)) => envelope.contains_rect(&other_envelope),
+            _ => true,
+        }
+    }
+
+    /// Where `point` lies relative to this `Polygon`, treating anything within `tolerance`
+    /// of an edge as on the boundary.
+    fn locate(&self, point: &Point, tolerance: f64) -> Location {
+        if self
+            .segments()
+            .any(|segment| segment.distance(point) <= tolerance)
+        {
+            Location::Boundary
+        } else if self.contains_point(point) {
+            Location::Interior
+        } else {
+            Location::Exterior
+        }
+    }
+}
+
+/// Whether a DE-9IM `matrix` matches the "contains properly" pattern `T**FF*FF*`.
+pub(crate) fn contains_matrix(matrix: &str) -> bool {
+    let matrix = matrix.as_bytes();
+    matrix[0] != b'F' && [3, 4, 6, 7].iter().all(|cell| matrix[*cell] == b'F')
+}
+
+/// Whether a DE-9IM `matrix` matches the covers pattern `T*****FF*`.
+pub(crate) fn covers_matrix(matrix: &str) -> bool {
+    let matrix = matrix.as_bytes();
+    matrix[0] != b'F' && matrix[6] == b'F' && matrix[7] == b'F'
+}
+
+/// The larger of the width and height of the box around both `Polygon`s, or 1 if that is 0.
+fn extent(polygon: &Polygon, other: &Polygon) -> f64 {
+    let coordinates = polygon
+        .iter()
+        .chain(other.iter())
+        .flat_map(|ring| ring.iter());
+    let (mut min, mut max) = ([f64::INFINITY; 2], [f64::NEG_INFINITY; 2]);
+    for coordinate in coordinates {
+        for axis in 0..2 {
+            min[axis] = min[axis].min(coordinate[axis]);
+            max[axis] = max[axis].max(coordinate[axis]);
+        }
+    }
+    let extent = (max[0] - min[0]).max(max[1] - min[1]);
+    if extent > 0. {
+        extent
+    } else {
+        1.
+    }
+}
+
+/// Raise the dimension recorded in a DE-9IM `cell` to at least `dimension`.
+fn raise(cell: &mut Option<u8>, dimension: u8) {
+    *cell = Some(cell.map_or(dimension, |current| current.max(dimension)));
+}
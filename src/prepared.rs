@@ -0,0 +1,181 @@
+use std::ops::Deref;
+
+use crate::line_string::LineSegment;
+use crate::point::Point;
+use crate::polygon::Polygon;
+use crate::predicates::{contains_matrix, covers_matrix};
+use crate::rect::Rect;
+use crate::traits::Envelope;
+
+type Edge = [[f64; 2]; 2];
+
+/// A geometry together with values computed from it once up front, to speed up repeated
+/// queries against it.
+///
+/// A `Prepared` geometry cannot be modified, so its cached values always match it. It
+/// dereferences to the geometry it wraps, so every other method remains available.
+///
+/// # Examples:
+///
+/// ```
+/// use auto_gis_with_rust::point::Point;
+/// use auto_gis_with_rust::polygon::Polygon;
+/// use auto_gis_with_rust::prepared::Prepared;
+///
+/// let polygon = Polygon::new(vec![vec![[0, 0], [4, 0], [4, 4], [0, 4]]]).unwrap();
+/// let prepared = Prepared::new(polygon.clone());
+///
+/// assert_eq!(prepared.area(), 16.);
+/// assert!(prepared.contains_point(&Point::new(1, 1)));
+/// assert_eq!(*prepared, polygon);
+/// assert_eq!(prepared.into_inner(), polygon);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Prepared<G> {
+    geometry: G,
+    area: f64,
+    envelope: Option<Rect>,
+    bands: Vec<Vec<Edge>>,
+}
+
+impl Prepared<Polygon> {
+    /// Prepare `polygon`, caching its area and envelope and sorting its edges into
+    /// horizontal bands.
+    ///
+    /// A query `Point` then only has to be compared with the edges in its band, rather
+    /// than every edge of the `Polygon`.
+    pub fn new(polygon: Polygon) -> Self {
+        let envelope = polygon.envelope();
+        let edges: Vec<Edge> = polygon
+            .iter()
+            .flat_map(|ring| ring.windows(2))
+            .map(|pair| [pair[0], pair[1]])
+            .collect();
+        let count = ((edges.len() as f64).sqrt().ceil() as usize).max(1);
+        let mut bands = vec![Vec::new(); count];
+        if let Some(envelope) = envelope {
+            let (min_y, height) = (envelope.min().y(), envelope.height());
+            for edge in edges {
+                let low = band_index(edge[0][1].min(edge[1][1]), min_y, height, count);
+                let high = band_index(edge[0][1].max(edge[1][1]), min_y, height, count);
+                for band in &mut bands[low..=high] {
+                    band.push(edge);
+                }
+            }
+        }
+        Prepared {
+            area: polygon.area(),
+            geometry: polygon,
+            envelope,
+            bands,
+        }
+    }
+
+    /// The area of the prepared `Polygon`, computed once on preparation.
+    pub fn area(&self) -> f64 {
+        self.area
+    }
+
+    /// The envelope of the prepared `Polygon`, computed once on preparation.
+    pub fn envelope(&self) -> Option<Rect> {
+        self.envelope
+    }
+
+    /// Returns true if `point` lies in the interior of the prepared `Polygon`, exactly as
+    /// [`Polygon::contains_point`] does.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::generators::point_grid;
+    /// use auto_gis_with_rust::generators::regular_polygon;
+    /// use auto_gis_with_rust::point::Point;
+    /// use auto_gis_with_rust::prepared::Prepared;
+    ///
+    /// let polygon = regular_polygon(Point::new(0, 0), 10., 1000).unwrap();
+    /// let prepared = Prepared::new(polygon.clone());
+    ///
+    /// for point in point_grid(Point::new(-12, -12), 50, 50, 0.5, 0.5).iter() {
+    ///     assert_eq!(prepared.contains_point(point), polygon.contains_point(point));
+    /// }
+    /// ```
+    pub fn contains_point(&self, point: &Point) -> bool {
+        self.contains_point_with(point, false)
+    }
+
+    /// Returns true if `point` lies inside the prepared `Polygon`, counting its boundary as
+    /// inside if `include_boundary` is true, exactly as [`Polygon::contains_point_with`]
+    /// does.
+    pub fn contains_point_with(&self, point: &Point, include_boundary: bool) -> bool {
+        let Some(envelope) = self.envelope else {
+            return false;
+        };
+        if !envelope.contains_rect(&Rect::new([point.x(), point.y()], [point.x(), point.y()])) {
+            return false;
+        }
+        let band = band_index(
+            point.y(),
+            envelope.min().y(),
+            envelope.height(),
+            self.bands.len(),
+        );
+        let edges = &self.bands[band];
+        if edges
+            .iter()
+            .any(|edge| LineSegment::new(*edge).distance(point) == 0.)
+        {
+            return include_boundary;
+        }
+        let crossings = edges
+            .iter()
+            .filter(|[[x_0, y_0], [x_1, y_1]]| {
+                (*y_0 > point.y()) != (*y_1 > point.y())
+                    && point.x() < x_0 + (point.y() - y_0) * (x_1 - x_0) / (y_1 - y_0)
+            })
+            .count();
+        crossings % 2 == 1
+    }
+
+    /// Returns true if `other` lies wholly in the interior of the prepared `Polygon`, as
+    /// [`Polygon::contains`] does, rejecting `other` straight away if its envelope is not
+    /// within the cached envelope.
+    pub fn contains(&self, other: &Polygon) -> bool {
+        self.envelope_contains(other) && contains_matrix(&self.geometry.relate(other))
+    }
+
+    /// Returns true if no part of `other` lies outside the prepared `Polygon`, as
+    /// [`Polygon::covers`] does, rejecting `other` straight away if its envelope is not
+    /// within the cached envelope.
+    pub fn covers(&self, other: &Polygon) -> bool {
+        self.envelope_contains(other) && covers_matrix(&self.geometry.relate(other))
+    }
+
+    /// Consume this `Prepared` geometry, returning the geometry it wraps.
+    pub fn into_inner(self) -> Polygon {
+        self.geometry
+    }
+
+    fn envelope_contains(&self, other: &Polygon) -> bool {
+        match (self.envelope, other.envelope()) {
+            (Some(envelope), Some(other_envelope)) => envelope.contains_rect(&other_envelope),
+            _ => true,
+        }
+    }
+}
+
+impl<G> Deref for Prepared<G> {
+    type Target = G;
+
+    fn deref(&self) -> &Self::Target {
+        &self.geometry
+    }
+}
+
+/// The band of `count` equal horizontal bands spanning `height` above `min_y` that `y`
+/// falls in, clamped to the first and last bands.
+fn band_index(y: f64, min_y: f64, height: f64, count: usize) -> usize {
+    if height == 0. {
+        return 0;
+    }
+    (((y - min_y) / height * count as f64) as usize).min(count - 1)
+}
@@ -0,0 +1,467 @@
+use crate::geometry::Geometry;
+use crate::line_string::{LineString, MultiLineString};
+use crate::point::{MultiPoint, Point};
+use crate::polygon::{MultiPolygon, Polygon};
+use crate::traits::MapCoordinates;
+
+const WGS84_SEMI_MAJOR_AXIS: f64 = 6_378_137.;
+const WGS84_FLATTENING: f64 = 1. / 298.257_223_563;
+const UTM_SCALE_FACTOR: f64 = 0.9996;
+const UTM_FALSE_EASTING: f64 = 500_000.;
+const UTM_FALSE_NORTHING: f64 = 10_000_000.;
+const WEB_MERCATOR_MAX_LATITUDE: f64 = 85.051_128_779_806_59;
+
+fn utm_central_meridian(zone: u8) -> f64 {
+    (zone as f64 - 1.) * 6. - 180. + 3.
+}
+
+/// Project a longitude/latitude `Point` in degrees into Universal Transverse Mercator
+/// coordinates on the WGS84 ellipsoid.
+///
+/// Returns the zone number, whether the point lies in the northern hemisphere, and the
+/// easting/northing in meters. Zones are the regular 6° bands; the Norway and Svalbard
+/// exceptions are not applied.
+///
+/// # Examples:
+///
+/// ```
+/// use auto_gis_with_rust::point::Point;
+/// use auto_gis_with_rust::projection::to_utm;
+///
+/// let cn_tower = Point::new(-79.387139, 43.642567);
+/// let (zone, north, utm) = to_utm(&cn_tower);
+///
+/// assert_eq!(zone, 17);
+/// assert!(north);
+/// assert!((utm.x() - 630084.).abs() < 1.);
+/// assert!((utm.y() - 4833439.).abs() < 1.);
+/// ```
+pub fn to_utm(p: &Point) -> (u8, bool, Point) {
+    let zone = (((p.x() + 180.) / 6.).floor() as i64 + 1).clamp(1, 60) as u8;
+    let north = p.y() >= 0.;
+    (zone, north, to_utm_zone(zone, north, p))
+}
+
+/// Project a longitude/latitude `Point` in degrees into Universal Transverse Mercator
+/// easting/northing in meters within `zone` and the hemisphere selected by `north`,
+/// whether or not the `Point` lies in that zone.
+fn to_utm_zone(zone: u8, north: bool, p: &Point) -> Point {
+    let a = WGS84_SEMI_MAJOR_AXIS;
+    let e2 = WGS84_FLATTENING * (2. - WGS84_FLATTENING);
+    let (e4, e6) = (e2 * e2, e2 * e2 * e2);
+    let ep2 = e2 / (1. - e2);
+    let phi = p.y().to_radians();
+    let (sin_phi, cos_phi, tan_phi) = (phi.sin(), phi.cos(), phi.tan());
+
+    let n = a / (1. - e2 * sin_phi * sin_phi).sqrt();
+    let t = tan_phi * tan_phi;
+    let c = ep2 * cos_phi * cos_phi;
+    let big_a = (p.x() - utm_central_meridian(zone)).to_radians() * cos_phi;
+    let m = a
+        * ((1. - e2 / 4. - 3. * e4 / 64. - 5. * e6 / 256.) * phi
+            - (3. * e2 / 8. + 3. * e4 / 32. + 45. * e6 / 1024.) * (2. * phi).sin()
+            + (15. * e4 / 256. + 45. * e6 / 1024.) * (4. * phi).sin()
+            - (35. * e6 / 3072.) * (6. * phi).sin());
+
+    let easting = UTM_SCALE_FACTOR
+        * n
+        * (big_a
+            + (1. - t + c) * big_a.powi(3) / 6.
+            + (5. - 18. * t + t * t + 72. * c - 58. * ep2) * big_a.powi(5) / 120.)
+        + UTM_FALSE_EASTING;
+    let mut northing = UTM_SCALE_FACTOR
+        * (m + n
+            * tan_phi
+            * (big_a.powi(2) / 2.
+                + (5. - t + 9. * c + 4. * c * c) * big_a.powi(4) / 24.
+                + (61. - 58. * t + t * t + 600. * c - 330. * ep2) * big_a.powi(6) / 720.));
+    if !north {
+        northing += UTM_FALSE_NORTHING;
+    }
+    Point::new(easting, northing)
+}
+
+/// Convert Universal Transverse Mercator easting/northing in meters within `zone` back
+/// into a longitude/latitude `Point` in degrees on the WGS84 ellipsoid.
+///
+/// `north` selects the hemisphere, as returned by `to_utm`.
+///
+/// # Examples:
+///
+/// ```
+/// use auto_gis_with_rust::point::Point;
+/// use auto_gis_with_rust::projection::{from_utm, to_utm};
+///
+/// let lon_lat = from_utm(17, true, &Point::new(630084., 4833439.));
+///
+/// assert!((lon_lat.x() - -79.387139).abs() < 1e-5);
+/// assert!((lon_lat.y() - 43.642567).abs() < 1e-5);
+///
+/// let sydney = Point::new(151.2093, -33.8688);
+/// let (zone, north, utm) = to_utm(&sydney);
+/// let back = from_utm(zone, north, &utm);
+///
+/// assert!((back.x() - sydney.x()).abs() < 1e-9);
+/// assert!((back.y() - sydney.y()).abs() < 1e-9);
+/// ```
+pub fn from_utm(zone: u8, north: bool, p: &Point) -> Point {
+    let a = WGS84_SEMI_MAJOR_AXIS;
+    let e2 = WGS84_FLATTENING * (2. - WGS84_FLATTENING);
+    let (e4, e6) = (e2 * e2, e2 * e2 * e2);
+    let ep2 = e2 / (1. - e2);
+    let e1 = (1. - (1. - e2).sqrt()) / (1. + (1. - e2).sqrt());
+
+    let x = p.x() - UTM_FALSE_EASTING;
+    let y = if north {
+        p.y()
+    } else {
+        p.y() - UTM_FALSE_NORTHING
+    };
+    let m = y / UTM_SCALE_FACTOR;
+    let mu = m / (a * (1. - e2 / 4. - 3. * e4 / 64. - 5. * e6 / 256.));
+    let phi1 = mu
+        + (3. * e1 / 2. - 27. * e1.powi(3) / 32.) * (2. * mu).sin()
+        + (21. * e1 * e1 / 16. - 55. * e1.powi(4) / 32.) * (4. * mu).sin()
+        + (151. * e1.powi(3) / 96.) * (6. * mu).sin()
+        + (1097. * e1.powi(4) / 512.) * (8. * mu).sin();
+    let (sin_phi1, cos_phi1, tan_phi1) = (phi1.sin(), phi1.cos(), phi1.tan());
+
+    let c1 = ep2 * cos_phi1 * cos_phi1;
+    let t1 = tan_phi1 * tan_phi1;
+    let n1 = a / (1. - e2 * sin_phi1 * sin_phi1).sqrt();
+    let r1 = a * (1. - e2) / (1. - e2 * sin_phi1 * sin_phi1).powf(1.5);
+    let d = x / (n1 * UTM_SCALE_FACTOR);
+
+    let phi = phi1
+        - (n1 * tan_phi1 / r1)
+            * (d * d / 2. - (5. + 3. * t1 + 10. * c1 - 4. * c1 * c1 - 9. * ep2) * d.powi(4) / 24.
+                + (61. + 90. * t1 + 298. * c1 + 45. * t1 * t1 - 252. * ep2 - 3. * c1 * c1)
+                    * d.powi(6)
+                    / 720.);
+    let lambda = (d - (1. + 2. * t1 + c1) * d.powi(3) / 6.
+        + (5. - 2. * c1 + 28. * t1 - 3. * c1 * c1 + 8. * ep2 + 24. * t1 * t1) * d.powi(5) / 120.)
+        / cos_phi1;
+
+    Point::new(
+        utm_central_meridian(zone) + lambda.to_degrees(),
+        phi.to_degrees(),
+    )
+}
+
+/// Project a longitude/latitude `Point` in degrees into Web Mercator (EPSG:3857)
+/// coordinates in meters.
+///
+/// Latitudes are clamped to ±85.0511°, the limit of the square Web Mercator world.
+///
+/// # Examples:
+///
+/// ```
+/// use auto_gis_with_rust::point::Point;
+/// use auto_gis_with_rust::projection::to_web_mercator;
+///
+/// assert_eq!(to_web_mercator(&Point::new(0., 0.)), Point::new(0., 0.));
+///
+/// let projected = to_web_mercator(&Point::new(45., 45.));
+///
+/// assert!((projected.x() - 5009377.085697311).abs() < 1e-6);
+/// assert!((projected.y() - 5621521.486192066).abs() < 1e-6);
+///
+/// let pole = to_web_mercator(&Point::new(180., 90.));
+///
+/// assert!((pole.x() - 20037508.342789244).abs() < 1e-6);
+/// assert!((pole.y() - 20037508.342789244).abs() < 1e-6);
+/// ```
+pub fn to_web_mercator(p: &Point) -> Point {
+    let latitude = p
+        .y()
+        .clamp(-WEB_MERCATOR_MAX_LATITUDE, WEB_MERCATOR_MAX_LATITUDE)
+        .to_radians();
+    Point::new(
+        WGS84_SEMI_MAJOR_AXIS * p.x().to_radians(),
+        WGS84_SEMI_MAJOR_AXIS * latitude.sin().atanh(),
+    )
+}
+
+/// Convert Web Mercator (EPSG:3857) coordinates in meters back into a longitude/latitude
+/// `Point` in degrees.
+///
+/// # Examples:
+///
+/// ```
+/// use auto_gis_with_rust::point::Point;
+/// use auto_gis_with_rust::projection::from_web_mercator;
+///
+/// assert_eq!(from_web_mercator(&Point::new(0., 0.)), Point::new(0., 0.));
+///
+/// let lon_lat = from_web_mercator(&Point::new(5009377.085697311, 5621521.486192066));
+///
+/// assert!((lon_lat.x() - 45.).abs() < 1e-9);
+/// assert!((lon_lat.y() - 45.).abs() < 1e-9);
+/// ```
+pub fn from_web_mercator(p: &Point) -> Point {
+    Point::new(
+        (p.x() / WGS84_SEMI_MAJOR_AXIS).to_degrees(),
+        (2. * (p.y() / WGS84_SEMI_MAJOR_AXIS).exp().atan() - std::f64::consts::FRAC_PI_2)
+            .to_degrees(),
+    )
+}
+
+/// A coordinate reference system that can convert its coordinates to and from
+/// longitude/latitude in degrees on WGS84, which serves as the hub between any two of them.
+pub trait Projection {
+    /// Convert `p` from this coordinate reference system into longitude/latitude in degrees.
+    fn to_wgs84(&self, p: &Point) -> Point;
+
+    /// Convert `p` from longitude/latitude in degrees into this coordinate reference system.
+    #[allow(clippy::wrong_self_convention)]
+    fn from_wgs84(&self, p: &Point) -> Point;
+
+    /// The longest segment, in this coordinate reference system's units, that
+    /// [`Reproject::reproject`] converts without first splitting it. Defaults to 1000,
+    /// a kilometre in the metre-based projections.
+    fn max_segment_length(&self) -> f64 {
+        1000.
+    }
+}
+
+/// Longitude/latitude in degrees on WGS84 (EPSG:4326), which needs no conversion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Wgs84;
+
+impl Projection for Wgs84 {
+    fn to_wgs84(&self, p: &Point) -> Point {
+        *p
+    }
+
+    fn from_wgs84(&self, p: &Point) -> Point {
+        *p
+    }
+
+    /// A hundredth of a degree, roughly a kilometre of latitude.
+    fn max_segment_length(&self) -> f64 {
+        0.01
+    }
+}
+
+/// Universal Transverse Mercator easting/northing in meters within a fixed `zone`, in the
+/// northern hemisphere if `north` is true and the southern one otherwise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Utm {
+    pub zone: u8,
+    pub north: bool,
+}
+
+impl Projection for Utm {
+    fn to_wgs84(&self, p: &Point) -> Point {
+        from_utm(self.zone, self.north, p)
+    }
+
+    fn from_wgs84(&self, p: &Point) -> Point {
+        to_utm_zone(self.zone, self.north, p)
+    }
+}
+
+/// Web Mercator (EPSG:3857) coordinates in meters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WebMercator;
+
+impl Projection for WebMercator {
+    fn to_wgs84(&self, p: &Point) -> Point {
+        from_web_mercator(p)
+    }
+
+    fn from_wgs84(&self, p: &Point) -> Point {
+        to_web_mercator(p)
+    }
+}
+
+/// Converts a geometry from one [`Projection`] into another.
+pub trait Reproject: MapCoordinates {
+    /// Return a copy of this geometry converted from the `from` coordinate reference
+    /// system into the `to` one, by way of WGS84.
+    ///
+    /// Long segments are densified first, as by
+    /// [`reproject_densified`](Reproject::reproject_densified) with `from`'s
+    /// [`max_segment_length`](Projection::max_segment_length): 1000 meters for [`Utm`]
+    /// and [`WebMercator`] and 0.01 degrees for [`Wgs84`]. The result therefore has more
+    /// vertices than this geometry whenever it has a longer segment.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::polygon::Polygon;
+    /// use auto_gis_with_rust::projection::{Reproject, Utm, WebMercator};
+    ///
+    /// let utm = Utm { zone: 17, north: true };
+    /// let field = Polygon::new(vec![vec![
+    ///     [630000., 4833000.],
+    ///     [640000., 4833000.],
+    ///     [640000., 4843000.],
+    ///     [630000., 4843000.],
+    /// ]])
+    /// .unwrap();
+    ///
+    /// let mercator = field.reproject(&utm, &WebMercator);
+    /// let back = mercator.reproject_densified(&WebMercator, &utm, 0.);
+    ///
+    /// assert_eq!(mercator[0].len(), 41);
+    /// assert!((mercator[0][0][0] - -8837464.).abs() < 1.);
+    /// // The UTM series expansions round-trip to well within a millimetre.
+    /// for (vertex, original) in back[0].iter().zip(field.densify(1000.)[0].iter()) {
+    ///     assert!((vertex[0] - original[0]).abs() < 1e-3);
+    ///     assert!((vertex[1] - original[1]).abs() < 1e-3);
+    /// }
+    /// ```
+    fn reproject(&self, from: &dyn Projection, to: &dyn Projection) -> Self {
+        self.reproject_densified(from, to, from.max_segment_length())
+    }
+
+    /// Return a copy of this geometry converted from the `from` coordinate reference
+    /// system into the `to` one, after splitting every segment longer than
+    /// `max_segment_length`, measured in `from`, into equal parts no longer than that.
+    ///
+    /// A straight segment in `from` generally follows a curve in `to`. Converting only its
+    /// ends replaces that curve with a straight chord, while the added vertices keep the
+    /// result within a small distance of the true path. A non-positive or infinite
+    /// `max_segment_length` converts the vertices only. Points have no segments and are
+    /// converted as they are.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    /// use auto_gis_with_rust::projection::{from_utm, Reproject, Utm, Wgs84};
+    ///
+    /// let utm = Utm { zone: 17, north: true };
+    /// let road = LineString::new(vec![[500000., 4800000.], [800000., 4800000.]]).unwrap();
+    ///
+    /// let straight = road.reproject_densified(&utm, &Wgs84, 0.);
+    /// let densified = road.reproject_densified(&utm, &Wgs84, 10000.);
+    ///
+    /// assert_eq!(straight.len(), 2);
+    /// assert_eq!(densified.len(), 31);
+    /// assert_eq!(densified[0], straight[0]);
+    /// assert_eq!(densified[30], straight[1]);
+    /// assert_eq!(road.reproject(&utm, &Wgs84).len(), 301);
+    ///
+    /// // Halfway along the road in UTM lies well north of the chord's midpoint in degrees.
+    /// let true_midpoint = from_utm(17, true, &road.interpolate(150000.));
+    /// let chord_midpoint = (straight[0][1] + straight[1][1]) / 2.;
+    ///
+    /// assert!((densified[15][1] - true_midpoint.y()).abs() < 1e-9);
+    /// assert!(true_midpoint.y() - chord_midpoint > 0.01);
+    /// ```
+    fn reproject_densified(
+        &self,
+        from: &dyn Projection,
+        to: &dyn Projection,
+        max_segment_length: f64,
+    ) -> Self;
+}
+
+/// Convert every vertex of `geometry` from `from` into `to`, leaving its segments as they
+/// are.
+fn convert_vertices<G: MapCoordinates>(
+    geometry: &G,
+    from: &dyn Projection,
+    to: &dyn Projection,
+) -> G {
+    geometry.map_coordinates(|coordinate| {
+        let projected = to.from_wgs84(&from.to_wgs84(&Point::from(coordinate)));
+        [projected.x(), projected.y()]
+    })
+}
+
+impl Reproject for Point {
+    fn reproject_densified(&self, from: &dyn Projection, to: &dyn Projection, _: f64) -> Self {
+        convert_vertices(self, from, to)
+    }
+}
+
+impl Reproject for MultiPoint {
+    fn reproject_densified(&self, from: &dyn Projection, to: &dyn Projection, _: f64) -> Self {
+        convert_vertices(self, from, to)
+    }
+}
+
+impl Reproject for LineString {
+    fn reproject_densified(
+        &self,
+        from: &dyn Projection,
+        to: &dyn Projection,
+        max_segment_length: f64,
+    ) -> Self {
+        convert_vertices(&self.densify(max_segment_length), from, to)
+    }
+}
+
+impl Reproject for MultiLineString {
+    fn reproject_densified(
+        &self,
+        from: &dyn Projection,
+        to: &dyn Projection,
+        max_segment_length: f64,
+    ) -> Self {
+        MultiLineString::new(
+            self.iter()
+                .map(|line_string| line_string.reproject_densified(from, to, max_segment_length))
+                .collect(),
+        )
+    }
+}
+
+impl Reproject for Polygon {
+    fn reproject_densified(
+        &self,
+        from: &dyn Projection,
+        to: &dyn Projection,
+        max_segment_length: f64,
+    ) -> Self {
+        convert_vertices(&self.densify(max_segment_length), from, to)
+    }
+}
+
+impl Reproject for MultiPolygon {
+    fn reproject_densified(
+        &self,
+        from: &dyn Projection,
+        to: &dyn Projection,
+        max_segment_length: f64,
+    ) -> Self {
+        MultiPolygon::new(
+            self.iter()
+                .map(|polygon| polygon.reproject_densified(from, to, max_segment_length))
+                .collect(),
+        )
+    }
+}
+
+impl Reproject for Geometry {
+    fn reproject_densified(
+        &self,
+        from: &dyn Projection,
+        to: &dyn Projection,
+        max_segment_length: f64,
+    ) -> Self {
+        match self {
+            Geometry::Point(point) => {
+                Geometry::Point(point.reproject_densified(from, to, max_segment_length))
+            }
+            Geometry::MultiPoint(multi_point) => {
+                Geometry::MultiPoint(multi_point.reproject_densified(from, to, max_segment_length))
+            }
+            Geometry::LineString(line_string) => {
+                Geometry::LineString(line_string.reproject_densified(from, to, max_segment_length))
+            }
+            Geometry::MultiLineString(multi_line_string) => Geometry::MultiLineString(
+                multi_line_string.reproject_densified(from, to, max_segment_length),
+            ),
+            Geometry::Polygon(polygon) => {
+                Geometry::Polygon(polygon.reproject_densified(from, to, max_segment_length))
+            }
+            Geometry::MultiPolygon(multi_polygon) => Geometry::MultiPolygon(
+                multi_polygon.reproject_densified(from, to, max_segment_length),
+            ),
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use std::f64::consts::PI;
+
+use rand::Rng;
+
+use crate::error::GeometryError;
+use crate::line_string::LineString;
+use crate::point::Point;
+use crate::polygon::Polygon;
+use crate::rect::Rect;
+
+/// Generate a `Point` drawn uniformly from within `bounds`.
+///
+/// Pass a seeded `rng` to generate the same `Point` every time.
+///
+/// # Examples:
+///
+/// ```
+/// use rand::{rngs::StdRng, SeedableRng};
+/// use auto_gis_with_rust::random::random_point;
+/// use auto_gis_with_rust::rect::Rect;
+///
+/// let bounds = Rect::new([0, 0], [10, 5]);
+/// let point = random_point(&mut StdRng::seed_from_u64(42), &bounds);
+///
+/// assert!((0. ..=10.).contains(&point.x()));
+/// assert!((0. ..=5.).contains(&point.y()));
+/// assert_eq!(point, random_point(&mut StdRng::seed_from_u64(42), &bounds));
+/// ```
+pub fn random_point<R: Rng + ?Sized>(rng: &mut R, bounds: &Rect) -> Point {
+    Point::new(
+        bounds.min().x() + rng.gen::<f64>() * bounds.width(),
+        bounds.min().y() + rng.gen::<f64>() * bounds.height(),
+    )
+}
+
+/// Generate a `LineString` through `n` `Point`s drawn uniformly from within `bounds`.
+///
+/// The `LineString` may cross itself.
+///
+/// # Examples:
+///
+/// ```
+/// use rand::{rngs::StdRng, SeedableRng};
+/// use auto_gis_with_rust::random::random_linestring;
+/// use auto_gis_with_rust::rect::Rect;
+///
+/// let bounds = Rect::new([0, 0], [10, 5]);
+/// let line_string = random_linestring(&mut StdRng::seed_from_u64(42), 20, &bounds).unwrap();
+///
+/// assert_eq!(line_string.len(), 20);
+/// assert!(random_linestring(&mut StdRng::seed_from_u64(42), 1, &bounds).is_err());
+/// ```
+pub fn random_linestring<R: Rng + ?Sized>(
+    rng: &mut R,
+    n: usize,
+    bounds: &Rect,
+) -> Result<LineString, GeometryError> {
+    let coordinates: Vec<[f64; 2]> = (0..n)
+        .map(|_| {
+            let point = random_point(rng, bounds);
+            [point.x(), point.y()]
+        })
+        .collect();
+    LineString::new(coordinates)
+}
+
+/// Generate a `Polygon` with a simple, counter-clockwise exterior ring through `n`
+/// `Point`s drawn from within `bounds`, and no holes.
+///
+/// The vertices are drawn at random distances around the center of `bounds`, one in each
+/// of `n` equal angular slots, and joined in order of angle. Every vertex lies less than
+/// half a turn from the next, so the center sees the whole ring and the ring never crosses
+/// itself.
+///
+/// # Examples:
+///
+/// ```
+/// use rand::{rngs::StdRng, SeedableRng};
+/// use auto_gis_with_rust::random::random_simple_polygon;
+/// use auto_gis_with_rust::rect::Rect;
+/// use auto_gis_with_rust::traits::Geometry;
+///
+/// let bounds = Rect::new([0, 0], [10, 5]);
+/// let mut rng = StdRng::seed_from_u64(42);
+///
+/// for _ in 0..100 {
+///     let polygon = random_simple_polygon(&mut rng, 12, &bounds).unwrap();
+///
+///     assert_eq!(polygon[0].len(), 13);
+///     assert!(polygon.is_valid());
+///     assert!(polygon.signed_area() > 0.);
+/// }
+/// assert!(random_simple_polygon(&mut rng, 2, &bounds).is_err());
+/// ```
+///
+/// Rings with only a few vertices are simple too.
+///
+/// ```
+/// use rand::{rngs::StdRng, SeedableRng};
+/// use auto_gis_with_rust::random::random_simple_polygon;
+/// use auto_gis_with_rust::rect::Rect;
+///
+/// let bounds = Rect::new([0, 0], [10, 10]);
+///
+/// for seed in 0..1000 {
+///     let mut rng = StdRng::seed_from_u64(seed);
+///
+///     for n in 3..6 {
+///         assert!(random_simple_polygon(&mut rng, n, &bounds).unwrap().is_valid());
+///     }
+/// }
+/// ```
+pub fn random_simple_polygon<R: Rng + ?Sized>(
+    rng: &mut R,
+    n: usize,
+    bounds: &Rect,
+) -> Result<Polygon, GeometryError> {
+    if n < 3 {
+        return Err(GeometryError::TooFewCoords(n));
+    }
+    let center = [
+        bounds.min().x() + bounds.width() / 2.,
+        bounds.min().y() + bounds.height() / 2.,
+    ];
+    let offset = rng.gen_range(-PI..PI);
+    let slot = 2. * PI / n as f64;
+    let ring: Vec<[f64; 2]> = (0..n)
+        .map(|index| {
+            let angle = offset + (index as f64 + 0.4 * rng.gen::<f64>()) * slot;
+            let distance = rng.gen_range(0.01f64..1.).sqrt();
+            [
+                center[0] + distance * angle.cos() * bounds.width() / 2.,
+                center[1] + distance * angle.sin() * bounds.height() / 2.,
+            ]
+        })
+        .collect();
+    Polygon::new(vec![ring])
+}
@@ -0,0 +1,257 @@
+use num_traits::NumCast;
+
+use crate::geometry::Geometry;
+use crate::point::Point;
+use crate::polygon::Polygon;
+use crate::traits::Envelope;
+
+/// An axis-aligned rectangle, stored as its minimum and maximum corners.
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub struct Rect([[f64; 2]; 2]);
+
+impl Rect {
+    /// Construct a new `Rect` spanning two opposite corners, given in any order.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::Point;
+    /// use auto_gis_with_rust::rect::Rect;
+    ///
+    /// let rect = Rect::new([2, 0], [0, 3]);
+    ///
+    /// assert_eq!(rect.min(), Point::new(0, 0));
+    /// assert_eq!(rect.max(), Point::new(2, 3));
+    /// ```
+    pub fn new<T: NumCast>(corner: [T; 2], opposite_corner: [T; 2]) -> Self {
+        let [x_0, y_0] = corner.map(|value| -> f64 { num_traits::cast(value).unwrap() });
+        let [x_1, y_1] = opposite_corner.map(|value| -> f64 { num_traits::cast(value).unwrap() });
+        Rect([[x_0.min(x_1), y_0.min(y_1)], [x_0.max(x_1), y_0.max(y_1)]])
+    }
+
+    /// The smallest `Rect` containing every coordinate in `coordinates`, or `None` if
+    /// there are none.
+    pub(crate) fn from_coordinates<I: IntoIterator<Item = [f64; 2]>>(
+        coordinates: I,
+    ) -> Option<Rect> {
+        coordinates.into_iter().fold(None, |rect, [x, y]| {
+            Some(match rect {
+                None => Rect([[x, y], [x, y]]),
+                Some(Rect([[min_x, min_y], [max_x, max_y]])) => {
+                    Rect([[min_x.min(x), min_y.min(y)], [max_x.max(x), max_y.max(y)]])
+                }
+            })
+        })
+    }
+
+    /// The corner of this `Rect` with the smallest coordinates.
+    pub fn min(&self) -> Point {
+        Point::from(self.0[0])
+    }
+
+    /// The corner of this `Rect` with the largest coordinates.
+    pub fn max(&self) -> Point {
+        Point::from(self.0[1])
+    }
+
+    /// The extent of this `Rect` along the x-axis.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::rect::Rect;
+    ///
+    /// assert_eq!(Rect::new([0, 0], [2, 3]).width(), 2.);
+    /// ```
+    pub fn width(&self) -> f64 {
+        self.0[1][0] - self.0[0][0]
+    }
+
+    /// The extent of this `Rect` along the y-axis.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::rect::Rect;
+    ///
+    /// assert_eq!(Rect::new([0, 0], [2, 3]).height(), 3.);
+    /// ```
+    pub fn height(&self) -> f64 {
+        self.0[1][1] - self.0[0][1]
+    }
+
+    /// Returns true if `other` lies wholly within this `Rect`, including on its edges.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::rect::Rect;
+    ///
+    /// let rect = Rect::new([0, 0], [4, 4]);
+    ///
+    /// assert!(rect.contains_rect(&Rect::new([0, 1], [2, 4])));
+    /// assert!(rect.contains_rect(&rect));
+    /// assert!(!rect.contains_rect(&Rect::new([3, 3], [5, 4])));
+    /// ```
+    pub fn contains_rect(&self, other: &Rect) -> bool {
+        let [[min_x, min_y], [max_x, max_y]] = self.0;
+        let [[other_min_x, other_min_y], [other_max_x, other_max_y]] = other.0;
+        min_x <= other_min_x && min_y <= other_min_y && other_max_x <= max_x && other_max_y <= max_y
+    }
+
+    /// Returns true if this `Rect` and `other` share any point, including on their edges.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::rect::Rect;
+    ///
+    /// let rect = Rect::new([0, 0], [2, 2]);
+    ///
+    /// assert!(rect.intersects(&Rect::new([1, 1], [3, 3])));
+    /// assert!(rect.intersects(&Rect::new([2, 0], [3, 1])));
+    /// assert!(!rect.intersects(&Rect::new([3, 0], [4, 1])));
+    /// ```
+    pub fn intersects(&self, other: &Rect) -> bool {
+        let [[min_x, min_y], [max_x, max_y]] = self.0;
+        let [[other_min_x, other_min_y], [other_max_x, other_max_y]] = other.0;
+        min_x <= other_max_x && other_min_x <= max_x && min_y <= other_max_y && other_min_y <= max_y
+    }
+
+    /// A copy of this `Rect` grown by `dx` on its left and right and by `dy` on its top
+    /// and bottom.
+    ///
+    /// Negative values shrink the `Rect`. A side shrunk past the center collapses onto
+    /// the center line instead of turning inside out.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::rect::Rect;
+    ///
+    /// let rect = Rect::new([0, 0], [4, 2]);
+    ///
+    /// assert_eq!(rect.expand(1., 0.5), Rect::new([-1., -0.5], [5., 2.5]));
+    /// assert_eq!(rect.expand(-1., -2.), Rect::new([1, 1], [3, 1]));
+    /// ```
+    pub fn expand(&self, dx: f64, dy: f64) -> Rect {
+        let [[min_x, min_y], [max_x, max_y]] = self.0;
+        let grow = |min: f64, max: f64, delta: f64| {
+            if max - min + 2. * delta < 0. {
+                let center = (min + max) / 2.;
+                [center, center]
+            } else {
+                [min - delta, max + delta]
+            }
+        };
+        let ([min_x, max_x], [min_y, max_y]) = (grow(min_x, max_x, dx), grow(min_y, max_y, dy));
+        Rect([[min_x, min_y], [max_x, max_y]])
+    }
+
+    /// The smallest `Rect` containing both this `Rect` and `other`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::rect::Rect;
+    ///
+    /// let rect = Rect::new([0, 0], [2, 2]);
+    ///
+    /// assert_eq!(rect.union(&Rect::new([1, -1], [3, 1])), Rect::new([0, -1], [3, 2]));
+    /// assert_eq!(rect.union(&Rect::new([5, 5], [6, 6])), Rect::new([0, 0], [6, 6]));
+    /// ```
+    pub fn union(&self, other: &Rect) -> Rect {
+        let [[min_x, min_y], [max_x, max_y]] = self.0;
+        let [[other_min_x, other_min_y], [other_max_x, other_max_y]] = other.0;
+        Rect([
+            [min_x.min(other_min_x), min_y.min(other_min_y)],
+            [max_x.max(other_max_x), max_y.max(other_max_y)],
+        ])
+    }
+
+    /// The `Rect` covered by both this `Rect` and `other`, or `None` if they do not
+    /// [`intersect`](Rect::intersects).
+    ///
+    /// `Rect`s that only touch give a `Rect` with no width or height.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::rect::Rect;
+    ///
+    /// let rect = Rect::new([0, 0], [2, 2]);
+    ///
+    /// assert_eq!(rect.intersection(&Rect::new([1, -1], [3, 1])), Some(Rect::new([1, 0], [2, 1])));
+    /// assert_eq!(rect.intersection(&Rect::new([2, 0], [3, 3])), Some(Rect::new([2, 0], [2, 2])));
+    /// assert_eq!(rect.intersection(&Rect::new([5, 5], [6, 6])), None);
+    /// ```
+    pub fn intersection(&self, other: &Rect) -> Option<Rect> {
+        if !self.intersects(other) {
+            return None;
+        }
+        let [[min_x, min_y], [max_x, max_y]] = self.0;
+        let [[other_min_x, other_min_y], [other_max_x, other_max_y]] = other.0;
+        Some(Rect([
+            [min_x.max(other_min_x), min_y.max(other_min_y)],
+            [max_x.min(other_max_x), max_y.min(other_max_y)],
+        ]))
+    }
+
+    /// This `Rect` as a `Polygon` with a counter-clockwise exterior ring starting at its
+    /// minimum corner.
+    ///
+    /// A `Rect` with no width or height becomes a `Polygon` with no area.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::rect::Rect;
+    ///
+    /// assert_eq!(
+    ///     Rect::new([0, 0], [2, 3]).to_polygon().to_string(),
+    ///     "POLYGON ((0 0, 2 0, 2 3, 0 3, 0 0))",
+    /// );
+    /// ```
+    pub fn to_polygon(&self) -> Polygon {
+        let [[min_x, min_y], [max_x, max_y]] = self.0;
+        Polygon::new(vec![vec![
+            [min_x, min_y],
+            [max_x, min_y],
+            [max_x, max_y],
+            [min_x, max_y],
+        ]])
+        .unwrap()
+    }
+}
+
+/// The smallest `Rect` containing the envelopes of all of `geometries`.
+///
+/// Empty geometries are skipped, and `None` is returned if there is nothing to bound.
+///
+/// # Examples:
+///
+/// ```
+/// use auto_gis_with_rust::geometry::Geometry;
+/// use auto_gis_with_rust::line_string::LineString;
+/// use auto_gis_with_rust::point::{MultiPoint, Point};
+/// use auto_gis_with_rust::polygon::Polygon;
+/// use auto_gis_with_rust::rect::{total_bounds, Rect};
+///
+/// let geometries = vec![
+///     Geometry::from(Point::new(-1, 2)),
+///     Geometry::from(LineString::new(vec![[0, 0], [3, 1]]).unwrap()),
+///     Geometry::from(Polygon::new(vec![vec![[1, 1], [2, 5], [2, 1]]]).unwrap()),
+///     Geometry::from(MultiPoint::new(vec![])),
+/// ];
+///
+/// assert_eq!(total_bounds(&geometries), Some(Rect::new([-1, 0], [3, 5])));
+/// assert_eq!(total_bounds(&[]), None);
+/// ```
+pub fn total_bounds(geometries: &[Geometry]) -> Option<Rect> {
+    Rect::from_coordinates(
+        geometries
+            .iter()
+            .filter_map(|geometry| geometry.envelope())
+            .flat_map(|rect| rect.0),
+    )
+}
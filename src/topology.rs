@@ -0,0 +1,292 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::helpers;
+use crate::line_string::{LineSegment, LineString, MultiLineString};
+use crate::point::Point;
+use crate::polygon::{MultiPolygon, Polygon};
+
+type Ring = Vec<[f64; 2]>;
+
+/// Build the `Polygon`s enclosed by a set of `LineString`s.
+///
+/// The `LineString`s must already be noded, i.e. they may only touch or cross each other
+/// at shared vertices. Edges that do not bound a face, such as dangling lines, are
+/// dropped. Every face becomes a `Polygon` with a counter-clockwise exterior ring, and
+/// groups of lines lying wholly inside a face become holes in that face.
+///
+/// # Examples:
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use auto_gis_with_rust::line_string::MultiLineString;
+/// use auto_gis_with_rust::topology::polygonize;
+///
+/// let lines = MultiLineString::try_from(vec![
+///     vec![[0., 0.], [1., 0.]],
+///     vec![[1., 0.], [1., 1.]],
+///     vec![[1., 1.], [0., 1.]],
+///     vec![[0., 1.], [0., 0.]],
+///     vec![[1., 1.], [2., 2.]],
+/// ]).unwrap();
+///
+/// assert_eq!(polygonize(&lines).to_string(), "MULTIPOLYGON (((0 0, 1 0, 1 1, 0 1, 0 0)))");
+/// ```
+///
+/// A closed line lying inside a face becomes a hole in it, as well as a `Polygon` of its own.
+///
+/// ```
+/// # use std::convert::TryFrom;
+/// # use auto_gis_with_rust::line_string::MultiLineString;
+/// # use auto_gis_with_rust::topology::polygonize;
+/// let lines = MultiLineString::try_from(vec![
+///     vec![[0., 0.], [3., 0.], [3., 3.], [0., 3.], [0., 0.]],
+///     vec![[1., 1.], [2., 1.], [2., 2.], [1., 2.], [1., 1.]],
+/// ]).unwrap();
+///
+/// assert_eq!(
+///     polygonize(&lines).to_string(),
+///     "MULTIPOLYGON (((0 0, 3 0, 3 3, 0 3, 0 0), (1 1, 1 2, 2 2, 2 1, 1 1)), ((1 1, 2 1, 2 2, 1 2, 1 1)))",
+/// );
+/// ```
+pub fn polygonize(lines: &MultiLineString) -> MultiPolygon {
+    let mut graph = PlanarGraph::new(lines);
+    graph.remove_dangles();
+
+    let (shells, holes): (Vec<Ring>, Vec<Ring>) = graph
+        .faces()
+        .into_iter()
+        .filter(|ring| helpers::signed_area(ring) != 0.)
+        .partition(|ring| helpers::signed_area(ring) > 0.);
+
+    let mut polygons: Vec<Vec<Ring>> = shells.into_iter().map(|shell| vec![shell]).collect();
+    for hole in holes {
+        let inside = Point::from(hole[0]);
+        let container = polygons
+            .iter_mut()
+            .filter(|rings| {
+                Polygon::new(vec![rings[0].clone()])
+                    .unwrap()
+                    .contains_point(&inside)
+            })
+            .min_by(|a, b| {
+                let a = helpers::signed_area(&a[0]);
+                let b = helpers::signed_area(&b[0]);
+                a.partial_cmp(&b).unwrap()
+            });
+        if let Some(rings) = container {
+            rings.push(hole);
+        }
+    }
+    MultiPolygon::new(
+        polygons
+            .into_iter()
+            .map(|rings| Polygon::new(rings).unwrap())
+            .collect(),
+    )
+}
+
+/// An undirected graph of the distinct vertices and segments of a set of `LineString`s.
+struct PlanarGraph {
+    nodes: Vec<[f64; 2]>,
+    neighbours: Vec<Vec<usize>>,
+}
+
+impl PlanarGraph {
+    fn new(lines: &MultiLineString) -> Self {
+        let mut graph = PlanarGraph {
+            nodes: Vec::new(),
+            neighbours: Vec::new(),
+        };
+        let mut indices: HashMap<[u64; 2], usize> = HashMap::new();
+        for line_string in lines.iter() {
+            let line_nodes: Vec<usize> = line_string
+                .iter()
+                .map(|coordinate| {
+                    *indices
+                        .entry(helpers::coordinate_key(*coordinate))
+                        .or_insert_with(|| {
+                            graph.nodes.push(*coordinate);
+                            graph.neighbours.push(Vec::new());
+                            graph.nodes.len() - 1
+                        })
+                })
+                .collect();
+            for pair in line_nodes.windows(2) {
+                let [from, to] = [pair[0], pair[1]];
+                if from != to && !graph.neighbours[from].contains(&to) {
+                    graph.neighbours[from].push(to);
+                    graph.neighbours[to].push(from);
+                }
+            }
+        }
+        for (node, neighbours) in graph.neighbours.iter_mut().enumerate() {
+            let [x, y] = graph.nodes[node];
+            let angle = |other: &usize| {
+                let [other_x, other_y] = graph.nodes[*other];
+                (other_y - y).atan2(other_x - x)
+            };
+            neighbours.sort_by(|a, b| angle(a).partial_cmp(&angle(b)).unwrap());
+        }
+        graph
+    }
+
+    /// Repeatedly remove edges leading to nodes with only one neighbour.
+    fn remove_dangles(&mut self) {
+        let mut dangles: Vec<usize> = (0..self.nodes.len())
+            .filter(|node| self.neighbours[*node].len() == 1)
+            .collect();
+        while let Some(node) = dangles.pop() {
+            if let Some(other) = self.neighbours[node].pop() {
+                self.neighbours[other].retain(|neighbour| *neighbour != node);
+                if self.neighbours[other].len() == 1 {
+                    dangles.push(other);
+                }
+            }
+        }
+    }
+
+    /// Trace the boundary of every face, keeping the face on the left. Bounded faces are
+    /// traced counter-clockwise and the outside of each connected group of edges clockwise.
+    fn faces(&self) -> Vec<Ring> {
+        let mut visited: HashSet<(usize, usize)> = HashSet::new();
+        let mut faces = Vec::new();
+        for start in 0..self.nodes.len() {
+            for first in self.neighbours[start].iter().copied() {
+                if visited.contains(&(start, first)) {
+                    continue;
+                }
+                let mut ring = vec![self.nodes[start]];
+                let (mut from, mut to) = (start, first);
+                loop {
+                    visited.insert((from, to));
+                    ring.push(self.nodes[to]);
+                    let neighbours = &self.neighbours[to];
+                    let back = neighbours.iter().position(|node| *node == from).unwrap();
+                    let next = neighbours[(back + neighbours.len() - 1) % neighbours.len()];
+                    (from, to) = (to, next);
+                    if (from, to) == (start, first) {
+                        break;
+                    }
+                }
+                faces.push(ring);
+            }
+        }
+        faces
+    }
+}
+
+/// Split every `LineString` wherever it crosses or touches another `LineString` or
+/// itself, so that the result is noded and ready for [`polygonize`].
+///
+/// Each crossing `Point` is inserted into every `LineString` passing through it, and
+/// each `LineString` is cut there into separate pieces.
+///
+/// # Examples:
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use auto_gis_with_rust::line_string::MultiLineString;
+/// use auto_gis_with_rust::topology::node;
+///
+/// let lines = MultiLineString::try_from(vec![
+///     vec![[0., 0.], [2., 2.]],
+///     vec![[0., 2.], [2., 0.]],
+/// ]).unwrap();
+///
+/// assert_eq!(
+///     node(&lines).to_string(),
+///     "MULTILINESTRING ((0 0, 1 1), (1 1, 2 2), (0 2, 1 1), (1 1, 2 0))",
+/// );
+/// ```
+///
+/// Vertices where a `LineString` meets nothing else are left in place.
+///
+/// ```
+/// # use std::convert::TryFrom;
+/// # use auto_gis_with_rust::line_string::MultiLineString;
+/// # use auto_gis_with_rust::topology::node;
+/// let lines = MultiLineString::try_from(vec![
+///     vec![[0., 0.], [2., 0.], [2., 2.]],
+///     vec![[1., -1.], [1., 1.]],
+/// ]).unwrap();
+///
+/// assert_eq!(
+///     node(&lines).to_string(),
+///     "MULTILINESTRING ((0 0, 1 0), (1 0, 2 0, 2 2), (1 -1, 1 0), (1 0, 1 1))",
+/// );
+/// ```
+pub fn node(lines: &MultiLineString) -> MultiLineString {
+    let segments: Vec<Vec<LineSegment>> = lines
+        .iter()
+        .map(|line_string| {
+            line_string
+                .windows(2)
+                .map(|pair| LineSegment::new([pair[0], pair[1]]))
+                .collect()
+        })
+        .collect();
+
+    let mut noded = Vec::new();
+    for (line, line_segments) in segments.iter().enumerate() {
+        let mut cut_vertices = vec![false; line_segments.len() + 1];
+        let mut splits: Vec<Vec<(f64, [f64; 2])>> = vec![Vec::new(); line_segments.len()];
+        for (index, segment) in line_segments.iter().enumerate() {
+            let others = segments
+                .iter()
+                .enumerate()
+                .flat_map(|(other_line, others)| {
+                    others
+                        .iter()
+                        .enumerate()
+                        .map(move |(other_index, other)| ((other_line, other_index), other))
+                });
+            for ((other_line, other_index), other) in others {
+                if (other_line, other_index) == (line, index) {
+                    continue;
+                }
+                let adjacent = other_line == line && index.abs_diff(other_index) == 1;
+                for coordinate in segment.intersection_coordinates(other) {
+                    let shared = if other_index > index {
+                        segment[1]
+                    } else {
+                        segment[0]
+                    };
+                    if adjacent && coordinate == shared {
+                        continue;
+                    }
+                    if coordinate == segment[0] {
+                        cut_vertices[index] = true;
+                    } else if coordinate == segment[1] {
+                        cut_vertices[index + 1] = true;
+                    } else {
+                        let fraction = (coordinate[0] - segment[0][0]) * segment.x_length()
+                            + (coordinate[1] - segment[0][1]) * segment.y_length();
+                        splits[index].push((fraction, coordinate));
+                    }
+                }
+            }
+        }
+
+        let mut piece = vec![line_segments[0][0]];
+        for (index, segment) in line_segments.iter().enumerate() {
+            splits[index].sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+            let ends = splits[index]
+                .iter()
+                .map(|(_, coordinate)| (*coordinate, true))
+                .chain([(segment[1], cut_vertices[index + 1])]);
+            for (coordinate, cut) in ends {
+                if piece[piece.len() - 1] != coordinate {
+                    piece.push(coordinate);
+                }
+                if cut && piece.len() > 1 {
+                    noded.push(LineString::new(piece).unwrap());
+                    piece = vec![coordinate];
+                }
+            }
+        }
+        if piece.len() > 1 {
+            noded.push(LineString::new(piece).unwrap());
+        }
+    }
+    MultiLineString::new(noded)
+}
@@ -1,4 +1,8 @@
+use crate::affine::Affine;
+use crate::error::GeometryError;
 use crate::point::Point;
+use crate::polygon::Polygon;
+use crate::rect::Rect;
 
 pub trait Geometry {
     fn centroid(&self) -> Point;
@@ -6,6 +10,89 @@ pub trait Geometry {
     fn is_simple(&self) -> bool;
 }
 
+pub trait Dimensions {
+    /// The topological dimension of this geometry: 0 for points, 1 for curves and 2 for
+    /// surfaces. For collections, this is the largest dimension of their members.
+    fn dimension(&self) -> u8;
+
+    /// The number of values that make up each coordinate: 2 for x and y, 3 when there is
+    /// also a z or m value and 4 when there are both.
+    fn coordinate_dimension(&self) -> u8 {
+        2
+    }
+}
+
+pub trait GeometryType {
+    /// The name of this geometry's type, e.g. `"Point"` or `"MultiPolygon"`, as used by
+    /// GeoJSON's `type` member.
+    fn geometry_type(&self) -> &'static str;
+}
+
+pub trait Envelope {
+    /// The smallest axis-aligned `Rect` containing this geometry, or `None` if it is
+    /// empty.
+    fn envelope(&self) -> Option<Rect>;
+
+    /// The envelope of this geometry as a `Polygon`, or `None` if it is empty.
+    ///
+    /// The envelope of a single `Point`, or of a geometry lying along a horizontal or
+    /// vertical line, is returned as a `Polygon` with no area rather than an error.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    /// use auto_gis_with_rust::point::{MultiPoint, Point};
+    /// use auto_gis_with_rust::traits::Envelope;
+    ///
+    /// let line_string = LineString::new(vec![[1, 2], [4, 0], [3, 5]]).unwrap();
+    ///
+    /// assert_eq!(
+    ///     line_string.envelope_polygon().unwrap().to_string(),
+    ///     "POLYGON ((1 0, 4 0, 4 5, 1 5, 1 0))",
+    /// );
+    /// assert_eq!(Point::new(1, 1).envelope_polygon().unwrap().area(), 0.);
+    /// assert_eq!(MultiPoint::new(vec![]).envelope_polygon(), None);
+    /// ```
+    fn envelope_polygon(&self) -> Option<Polygon> {
+        self.envelope().map(|rect| rect.to_polygon())
+    }
+}
+
+pub trait ConvexHull {
+    /// The smallest convex `Polygon` containing every vertex of this geometry.
+    ///
+    /// The exterior ring runs counter-clockwise. Returns `GeometryError::TooFewCoords` if
+    /// there are fewer than three non-collinear vertices.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    /// use auto_gis_with_rust::polygon::Polygon;
+    /// use auto_gis_with_rust::traits::ConvexHull;
+    ///
+    /// let u_shape = Polygon::new(vec![vec![
+    ///     [0, 0], [3, 0], [3, 3], [2, 3], [2, 1], [1, 1], [1, 3], [0, 3],
+    /// ]]).unwrap();
+    /// let straight = LineString::new(vec![[0, 0], [1, 1], [2, 2]]).unwrap();
+    ///
+    /// assert_eq!(
+    ///     u_shape.convex_hull().unwrap().to_string(),
+    ///     "POLYGON ((0 0, 3 0, 3 3, 0 3, 0 0))",
+    /// );
+    /// assert!(straight.convex_hull().is_err());
+    /// ```
+    fn convex_hull(&self) -> Result<Polygon, GeometryError>;
+}
+
+pub trait EqualsExact {
+    /// Returns true if this geometry has the same structure as `other`, with every
+    /// coordinate no further than `tolerance` from the corresponding coordinate of
+    /// `other`.
+    fn equals_exact(&self, other: &Self, tolerance: f64) -> bool;
+}
+
 pub trait GeometryCollection<T: Geometry> {
     fn num_geometries(&self) -> usize;
 
@@ -29,3 +116,137 @@ pub trait LineString: Curve {
 
     fn point_n(&self, number: usize) -> Point;
 }
+
+pub trait MapCoordinates: Sized {
+    /// Return a copy of this geometry with `function` applied to every coordinate.
+    fn map_coordinates<F: Fn([f64; 2]) -> [f64; 2]>(&self, function: F) -> Self;
+
+    /// Return a copy of this geometry with `function` applied to every coordinate,
+    /// spreading the work across threads with `rayon`.
+    ///
+    /// The default applies `function` serially, as `map_coordinates` does; geometries with
+    /// many coordinates override it to split the work.
+    #[cfg(feature = "parallel")]
+    fn par_map_coordinates<F: Fn([f64; 2]) -> [f64; 2] + Sync + Send>(&self, function: F) -> Self {
+        self.map_coordinates(function)
+    }
+
+    /// Return a copy of this geometry with `affine` applied to every coordinate.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::affine::Affine;
+    /// use auto_gis_with_rust::line_string::LineString;
+    /// use auto_gis_with_rust::traits::MapCoordinates;
+    ///
+    /// let line_string = LineString::new(vec![[0., 0.], [1., 2.]]).unwrap();
+    /// let moved = line_string.transform(&Affine::translation(1., 1.));
+    ///
+    /// assert_eq!(moved.to_string(), "LINESTRING (1 1, 2 3)");
+    /// ```
+    fn transform(&self, affine: &Affine) -> Self {
+        self.map_coordinates(|coordinate| affine.apply(coordinate))
+    }
+
+    /// Return a copy of this geometry with `affine` applied to every coordinate, spreading
+    /// the work across threads with `rayon`.
+    ///
+    /// The result is identical to `transform`; this only pays off for geometries with
+    /// very many vertices.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::affine::Affine;
+    /// use auto_gis_with_rust::generators::regular_polygon;
+    /// use auto_gis_with_rust::point::Point;
+    /// use auto_gis_with_rust::traits::MapCoordinates;
+    ///
+    /// let polygon = regular_polygon(Point::ORIGIN, 1000., 10_000).unwrap();
+    /// let affine = Affine::rotation(30., Point::new(10, 20)).then(&Affine::translation(5., -5.));
+    ///
+    /// assert_eq!(polygon.par_transform(&affine), polygon.transform(&affine));
+    /// ```
+    #[cfg(feature = "parallel")]
+    fn par_transform(&self, affine: &Affine) -> Self {
+        self.par_map_coordinates(|coordinate| affine.apply(coordinate))
+    }
+
+    /// Return a copy of this geometry with every coordinate snapped to the nearest
+    /// node of a grid with cells of `size`, anchored at `origin`.
+    ///
+    /// Snapping can collapse adjacent vertices onto the same node, so rings may need
+    /// to be re-validated afterwards.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    /// use auto_gis_with_rust::point::Point;
+    /// use auto_gis_with_rust::traits::MapCoordinates;
+    ///
+    /// let line_string = LineString::new(vec![[0.1, 0.9], [2.4, 1.6]]).unwrap();
+    /// let snapped = line_string.snap_to_grid(1., Point::new(0.5, 0.5));
+    ///
+    /// assert_eq!(snapped.to_string(), "LINESTRING (0.5 0.5, 2.5 1.5)");
+    /// ```
+    fn snap_to_grid(&self, size: f64, origin: Point) -> Self {
+        self.map_coordinates(|[x, y]| {
+            [
+                origin.x() + ((x - origin.x()) / size).round() * size,
+                origin.y() + ((y - origin.y()) / size).round() * size,
+            ]
+        })
+    }
+
+    /// Return a copy of this geometry with every coordinate rounded to `decimals`
+    /// decimal places.
+    ///
+    /// Every coordinate is rounded independently, so a closed ring stays closed.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::polygon::Polygon;
+    /// use auto_gis_with_rust::traits::MapCoordinates;
+    ///
+    /// let polygon = Polygon::new(vec![vec![[0.123, 0.456], [0.987, 0.654], [0.5, 1.]]]).unwrap();
+    /// let rounded = polygon.round_coordinates(1);
+    ///
+    /// assert_eq!(rounded.to_string(), "POLYGON ((0.1 0.5, 1 0.7, 0.5 1, 0.1 0.5))");
+    /// ```
+    fn round_coordinates(&self, decimals: u32) -> Self {
+        let factor = 10f64.powi(decimals as i32);
+        self.map_coordinates(|coordinate| coordinate.map(|value| (value * factor).round() / factor))
+    }
+
+    /// Return a copy of this geometry with every x coordinate, taken as a longitude in
+    /// degrees, wrapped into the range `-180` to `180`.
+    ///
+    /// Each coordinate is wrapped on its own: a geometry crossing the antimeridian is not
+    /// split, so its edges across it will run the long way round the globe afterwards.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    /// use auto_gis_with_rust::traits::MapCoordinates;
+    ///
+    /// let line_string = LineString::new(vec![[170, 0], [190, 10], [-540, 20]]).unwrap();
+    ///
+    /// assert_eq!(
+    ///     line_string.wrap_longitudes().to_string(),
+    ///     "LINESTRING (170 0, -170 10, -180 20)",
+    /// );
+    /// ```
+    fn wrap_longitudes(&self) -> Self {
+        self.map_coordinates(|[x, y]| {
+            if (-180. ..=180.).contains(&x) {
+                [x, y]
+            } else {
+                [(x + 180.).rem_euclid(360.) - 180., y]
+            }
+        })
+    }
+}
@@ -0,0 +1,81 @@
+/// A unit of length.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum LengthUnit {
+    Meter,
+    Kilometer,
+    Foot,
+    Mile,
+    NauticalMile,
+}
+
+impl LengthUnit {
+    /// The length of one of this unit in meters, using the international foot and mile.
+    pub fn meters(&self) -> f64 {
+        match self {
+            LengthUnit::Meter => 1.,
+            LengthUnit::Kilometer => 1_000.,
+            LengthUnit::Foot => 0.304_8,
+            LengthUnit::Mile => 1_609.344,
+            LengthUnit::NauticalMile => 1_852.,
+        }
+    }
+}
+
+/// A unit of area.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum AreaUnit {
+    SquareMeter,
+    Hectare,
+    Acre,
+    SquareKilometer,
+}
+
+impl AreaUnit {
+    /// The area of one of this unit in square meters, using the international acre.
+    pub fn square_meters(&self) -> f64 {
+        match self {
+            AreaUnit::SquareMeter => 1.,
+            AreaUnit::Hectare => 10_000.,
+            AreaUnit::Acre => 4_046.856_422_4,
+            AreaUnit::SquareKilometer => 1_000_000.,
+        }
+    }
+}
+
+/// Convert a length of `value` in the unit `from` into the unit `to`.
+///
+/// # Examples:
+///
+/// ```
+/// use auto_gis_with_rust::units::{convert_length, LengthUnit};
+///
+/// assert_eq!(convert_length(1., LengthUnit::Mile, LengthUnit::Foot), 5280.);
+/// assert_eq!(convert_length(2., LengthUnit::NauticalMile, LengthUnit::Meter), 3704.);
+/// assert_eq!(convert_length(1500., LengthUnit::Meter, LengthUnit::Kilometer), 1.5);
+/// ```
+pub fn convert_length(value: f64, from: LengthUnit, to: LengthUnit) -> f64 {
+    if from == to {
+        value
+    } else {
+        value * from.meters() / to.meters()
+    }
+}
+
+/// Convert an area of `value` in the unit `from` into the unit `to`.
+///
+/// # Examples:
+///
+/// ```
+/// use auto_gis_with_rust::units::{convert_area, AreaUnit};
+///
+/// assert_eq!(convert_area(25_000., AreaUnit::SquareMeter, AreaUnit::Hectare), 2.5);
+/// assert_eq!(convert_area(1., AreaUnit::SquareKilometer, AreaUnit::Hectare), 100.);
+/// assert!((convert_area(1., AreaUnit::Hectare, AreaUnit::Acre) - 2.471_054).abs() < 1e-6);
+/// ```
+pub fn convert_area(value: f64, from: AreaUnit, to: AreaUnit) -> f64 {
+    if from == to {
+        value
+    } else {
+        value * from.square_meters() / to.square_meters()
+    }
+}
@@ -0,0 +1,267 @@
+use std::collections::HashSet;
+
+use crate::error::GeometryError;
+use crate::helpers;
+use crate::line_string::self_intersections;
+use crate::overlay::overlay;
+use crate::point::Point;
+use crate::polygon::{MultiPolygon, Polygon};
+
+impl Polygon {
+    /// Returns true if this `Polygon` is valid in the OGC Simple Features sense.
+    ///
+    /// A valid `Polygon` has rings of at least three distinct, non-collinear vertices that
+    /// do not cross or touch themselves. Its rings may only touch each other at a single
+    /// point, never cross, and must not touch in a way that cuts its interior in two. Every
+    /// hole must lie inside the exterior ring and outside every other hole. The winding of
+    /// the rings does not matter.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::polygon::Polygon;
+    ///
+    /// let square = Polygon::new(vec![vec![[0, 0], [2, 0], [2, 2], [0, 2]]]).unwrap();
+    /// let bowtie = Polygon::new(vec![vec![[0, 0], [2, 2], [2, 0], [0, 2]]]).unwrap();
+    /// let hole_outside = Polygon::new(vec![
+    ///     vec![[0, 0], [2, 0], [2, 2], [0, 2]],
+    ///     vec![[3, 3], [4, 3], [4, 4], [3, 4]],
+    /// ]).unwrap();
+    ///
+    /// assert!(square.is_valid());
+    /// assert!(!bowtie.is_valid());
+    /// assert!(!hole_outside.is_valid());
+    /// ```
+    ///
+    /// Holes may touch the exterior ring and each other at single points, as long as the
+    /// interior stays connected, even when every vertex of a hole is such a point.
+    ///
+    /// ```
+    /// # use auto_gis_with_rust::polygon::Polygon;
+    /// let touching_holes = Polygon::new(vec![
+    ///     vec![[0, 0], [10, 0], [10, 10], [0, 10]],
+    ///     vec![[0, 5], [5, 2], [5, 8]],
+    ///     vec![[5, 2], [8, 1], [8, 3]],
+    ///     vec![[5, 8], [8, 7], [8, 9]],
+    /// ]).unwrap();
+    ///
+    /// assert!(touching_holes.is_valid());
+    /// ```
+    pub fn is_valid(&self) -> bool {
+        self.validity_reason().is_ok()
+    }
+
+    /// Check that this `Polygon` is valid, as [`is_valid`](Polygon::is_valid) does,
+    /// returning a description of the first problem found and where it lies if not.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::Point;
+    /// use auto_gis_with_rust::polygon::Polygon;
+    ///
+    /// let square = Polygon::new(vec![vec![[0, 0], [2, 0], [2, 2], [0, 2]]]).unwrap();
+    /// let bowtie = Polygon::new(vec![vec![[0, 0], [2, 2], [2, 0], [0, 2]]]).unwrap();
+    /// let hole_outside = Polygon::new(vec![
+    ///     vec![[0, 0], [2, 0], [2, 2], [0, 2]],
+    ///     vec![[3, 3], [4, 3], [4, 4], [3, 4]],
+    /// ]).unwrap();
+    ///
+    /// assert_eq!(square.validity_reason(), Ok(()));
+    /// assert_eq!(
+    ///     bowtie.validity_reason(),
+    ///     Err(("ring self-intersection".to_string(), Point::new(1, 1))),
+    /// );
+    /// assert_eq!(
+    ///     hole_outside.validity_reason(),
+    ///     Err(("hole lies outside the exterior ring".to_string(), Point::new(3, 3))),
+    /// );
+    /// ```
+    ///
+    /// Holes touching other rings at every one of their vertices are checked without
+    /// panicking.
+    ///
+    /// ```
+    /// # use auto_gis_with_rust::polygon::Polygon;
+    /// let touching_holes = Polygon::new(vec![
+    ///     vec![[0, 0], [10, 0], [10, 10], [0, 10]],
+    ///     vec![[0, 5], [5, 2], [5, 8]],
+    ///     vec![[5, 2], [8, 1], [8, 3]],
+    ///     vec![[5, 8], [8, 7], [8, 9]],
+    /// ]).unwrap();
+    ///
+    /// assert_eq!(touching_holes.validity_reason(), Ok(()));
+    /// ```
+    pub fn validity_reason(&self) -> Result<(), (String, Point)> {
+        let invalid =
+            |reason: &str, coordinate: [f64; 2]| Err((reason.to_string(), Point::from(coordinate)));
+        for ring in self.iter() {
+            if ring.len() < 4 {
+                return invalid("too few points in ring", ring[0]);
+            }
+            let mut vertices = ring.to_vec();
+            vertices.dedup();
+            if let Some((_, _, coordinate)) = self_intersections(&vertices).first() {
+                return invalid("ring self-intersection", *coordinate);
+            }
+            if ring.signed_area() == 0. {
+                return invalid("ring has no area", ring[0]);
+            }
+        }
+
+        // Two rings may touch at one point at most. Linking every pair of touching rings
+        // must not close a loop, as a loop of touching rings cuts off part of the interior.
+        let mut groups: Vec<usize> = (0..self.len()).collect();
+        let find = |groups: &Vec<usize>, mut index: usize| {
+            while groups[index] != index {
+                index = groups[index];
+            }
+            index
+        };
+        let mut touches = vec![HashSet::new(); self.len()];
+        for index in 0..self.len() {
+            for other_index in index + 1..self.len() {
+                let mut keys = HashSet::new();
+                let shared: Vec<[f64; 2]> = self[index]
+                    .segments()
+                    .into_iter()
+                    .flat_map(|segment| {
+                        self[other_index]
+                            .segments()
+                            .into_iter()
+                            .flat_map(move |other| segment.intersection_coordinates(&other))
+                    })
+                    .filter(|coordinate| keys.insert(helpers::coordinate_key(*coordinate)))
+                    .collect();
+                if shared.len() > 1 {
+                    return invalid("rings cross or touch at more than one point", shared[1]);
+                }
+                if let Some(coordinate) = shared.first() {
+                    let (group, other_group) = (find(&groups, index), find(&groups, other_index));
+                    if group == other_group {
+                        return invalid("interior is disconnected", *coordinate);
+                    }
+                    groups[group] = other_group;
+                }
+                touches[index].extend(keys.iter().copied());
+                touches[other_index].extend(keys);
+            }
+        }
+
+        // Rings that do not cross lie wholly inside or outside each other, which any point
+        // of a ring off its shared points can tell. Every vertex of a ring may be shared, in
+        // which case a point part of the way along one of its edges is used instead. An edge
+        // holds at most as many shared points as the ring has, so one of that many plus one
+        // positions along it is free.
+        let probe = |ring: usize| -> [f64; 2] {
+            let free = |coordinate: &[f64; 2]| {
+                !touches[ring].contains(&helpers::coordinate_key(*coordinate))
+            };
+            let along_edges = self[ring].windows(2).flat_map(|pair| {
+                let [[x_0, y_0], [x_1, y_1]] = [pair[0], pair[1]];
+                (2..touches[ring].len() + 3).map(move |parts| {
+                    let fraction = 1. / parts as f64;
+                    [x_0 + fraction * (x_1 - x_0), y_0 + fraction * (y_1 - y_0)]
+                })
+            });
+            self[ring]
+                .iter()
+                .copied()
+                .chain(along_edges)
+                .find(free)
+                .unwrap_or(self[ring][0])
+        };
+        let inside = |ring: usize, container: usize| {
+            Polygon::new(vec![self[container].to_vec()])
+                .unwrap()
+                .contains_point(&Point::from(probe(ring)))
+        };
+        for hole in 1..self.len() {
+            if !inside(hole, 0) {
+                return invalid("hole lies outside the exterior ring", probe(hole));
+            }
+            if (1..self.len()).any(|other| other != hole && inside(hole, other)) {
+                return invalid("hole lies inside another hole", probe(hole));
+            }
+        }
+        Ok(())
+    }
+
+    /// Repair this `Polygon` into a valid `MultiPolygon` covering the same area.
+    ///
+    /// The rings are noded at every crossing and polygonized into faces, and a face is kept
+    /// if it lies inside an odd number of rings. A self-intersecting ring therefore falls
+    /// apart into its loops, while a hole lying outside the exterior ring becomes a
+    /// `Polygon` of its own. Every part of the result has a counter-clockwise exterior ring
+    /// and clockwise holes, and parts that collapse to no area are dropped.
+    ///
+    /// # Examples:
+    ///
+    /// A bowtie is split into two triangles touching at its crossing point.
+    ///
+    /// ```
+    /// use auto_gis_with_rust::polygon::Polygon;
+    ///
+    /// let bowtie = Polygon::new(vec![vec![[0, 0], [2, 2], [2, 0], [0, 2]]]).unwrap();
+    /// let valid = bowtie.make_valid().unwrap();
+    ///
+    /// assert_eq!(
+    ///     valid.to_string(),
+    ///     "MULTIPOLYGON (((0 0, 1 1, 0 2, 0 0)), ((1 1, 2 0, 2 2, 1 1)))",
+    /// );
+    /// assert!(valid.is_valid());
+    /// assert!(valid.iter().all(|triangle| triangle.is_valid()));
+    /// ```
+    ///
+    /// A clockwise exterior ring with a counter-clockwise hole is reoriented.
+    ///
+    /// ```
+    /// # use auto_gis_with_rust::polygon::Polygon;
+    /// let polygon = Polygon::new(vec![
+    ///     vec![[0, 0], [0, 3], [3, 3], [3, 0]],
+    ///     vec![[1, 1], [2, 1], [2, 2], [1, 2]],
+    /// ]).unwrap();
+    ///
+    /// assert_eq!(
+    ///     polygon.make_valid().unwrap().to_string(),
+    ///     "MULTIPOLYGON (((0 0, 3 0, 3 3, 0 3, 0 0), (1 1, 1 2, 2 2, 2 1, 1 1)))",
+    /// );
+    /// ```
+    pub fn make_valid(&self) -> Result<MultiPolygon, GeometryError> {
+        overlay(&[&[self]], |inside| inside[0])
+    }
+}
+
+impl MultiPolygon {
+    /// Returns true if this `MultiPolygon` is valid in the OGC Simple Features sense: every
+    /// `Polygon` in it is valid, and no two of them overlap or share part of an edge,
+    /// though they may touch at points.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use auto_gis_with_rust::polygon::MultiPolygon;
+    ///
+    /// let touching = MultiPolygon::try_from(vec![
+    ///     vec![vec![[0, 0], [1, 0], [1, 1], [0, 1]]],
+    ///     vec![vec![[1, 1], [2, 1], [2, 2], [1, 2]]],
+    /// ]).unwrap();
+    /// let adjacent = MultiPolygon::try_from(vec![
+    ///     vec![vec![[0, 0], [1, 0], [1, 1], [0, 1]]],
+    ///     vec![vec![[1, 0], [2, 0], [2, 1], [1, 1]]],
+    /// ]).unwrap();
+    ///
+    /// assert!(touching.is_valid());
+    /// assert!(!adjacent.is_valid());
+    /// ```
+    pub fn is_valid(&self) -> bool {
+        self.iter().all(Polygon::is_valid)
+            && self.iter().enumerate().all(|(index, polygon)| {
+                self.iter().skip(index + 1).all(|other| {
+                    let matrix = polygon.relate(other).into_bytes();
+                    matrix[0] == b'F' && matrix[4] != b'1'
+                })
+            })
+    }
+}
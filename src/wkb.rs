@@ -0,0 +1,383 @@
+use crate::error::GeometryError;
+use crate::geometry::Geometry;
+use crate::line_string::{LineString, MultiLineString};
+use crate::point::{MultiPoint, Point};
+use crate::polygon::{MultiPolygon, Polygon};
+
+const EWKB_Z_FLAG: u32 = 0x8000_0000;
+const EWKB_M_FLAG: u32 = 0x4000_0000;
+const EWKB_SRID_FLAG: u32 = 0x2000_0000;
+
+/// The order in which the bytes of multi-byte numbers are written.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ByteOrder {
+    BigEndian,
+    LittleEndian,
+}
+
+/// Serializes a geometry into the binary WKB and PostGIS EWKB formats.
+pub trait ToWkb {
+    /// Append this geometry to `buffer`, tagging it with `srid` if one is given.
+    fn write_wkb(&self, buffer: &mut Vec<u8>, order: ByteOrder, srid: Option<u32>);
+
+    /// This geometry as Well-Known Binary.
+    ///
+    /// `Point`s with z or m values use the ISO type codes, e.g. 1001 for `POINT Z`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::Point;
+    /// use auto_gis_with_rust::wkb::{ByteOrder, ToWkb};
+    ///
+    /// let wkb = Point::new(1, 2).to_wkb(ByteOrder::BigEndian);
+    ///
+    /// assert_eq!(wkb, [
+    ///     0, 0, 0, 0, 1,
+    ///     63, 240, 0, 0, 0, 0, 0, 0,
+    ///     64, 0, 0, 0, 0, 0, 0, 0,
+    /// ]);
+    /// ```
+    fn to_wkb(&self, order: ByteOrder) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        self.write_wkb(&mut buffer, order, None);
+        buffer
+    }
+
+    /// This geometry as PostGIS Extended Well-Known Binary, tagged with `srid`.
+    ///
+    /// The SRID flag is set in the type code of the outermost geometry, followed by the
+    /// SRID itself. Z and m values are flagged the same way rather than with ISO type codes.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::Point;
+    /// use auto_gis_with_rust::wkb::{ByteOrder, ToWkb};
+    ///
+    /// // SELECT ST_AsEWKB('SRID=4326;POINT(1 2)');
+    /// let expected = "0101000020E6100000000000000000F03F0000000000000040";
+    /// let ewkb = Point::new(1, 2).to_ewkb(4326, ByteOrder::LittleEndian);
+    /// let hex: String = ewkb.iter().map(|byte| format!("{:02X}", byte)).collect();
+    ///
+    /// assert_eq!(hex, expected);
+    /// ```
+    fn to_ewkb(&self, srid: u32, order: ByteOrder) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        self.write_wkb(&mut buffer, order, Some(srid));
+        buffer
+    }
+}
+
+fn write_u32(buffer: &mut Vec<u8>, order: ByteOrder, value: u32) {
+    match order {
+        ByteOrder::BigEndian => buffer.extend(value.to_be_bytes()),
+        ByteOrder::LittleEndian => buffer.extend(value.to_le_bytes()),
+    }
+}
+
+fn write_f64(buffer: &mut Vec<u8>, order: ByteOrder, value: f64) {
+    match order {
+        ByteOrder::BigEndian => buffer.extend(value.to_be_bytes()),
+        ByteOrder::LittleEndian => buffer.extend(value.to_le_bytes()),
+    }
+}
+
+fn write_header(buffer: &mut Vec<u8>, order: ByteOrder, type_code: u32, srid: Option<u32>) {
+    buffer.push(match order {
+        ByteOrder::BigEndian => 0,
+        ByteOrder::LittleEndian => 1,
+    });
+    match srid {
+        Some(srid) => {
+            write_u32(buffer, order, type_code | EWKB_SRID_FLAG);
+            write_u32(buffer, order, srid);
+        }
+        None => write_u32(buffer, order, type_code),
+    }
+}
+
+fn write_coordinates(buffer: &mut Vec<u8>, order: ByteOrder, coordinates: &[[f64; 2]]) {
+    write_u32(buffer, order, coordinates.len() as u32);
+    for coordinate in coordinates {
+        write_f64(buffer, order, coordinate[0]);
+        write_f64(buffer, order, coordinate[1]);
+    }
+}
+
+impl ToWkb for Point {
+    fn write_wkb(&self, buffer: &mut Vec<u8>, order: ByteOrder, srid: Option<u32>) {
+        let type_code = match srid {
+            Some(_) => {
+                1 | if self.z().is_some() { EWKB_Z_FLAG } else { 0 }
+                    | if self.m().is_some() { EWKB_M_FLAG } else { 0 }
+            }
+            None => {
+                1 + if self.z().is_some() { 1000 } else { 0 }
+                    + if self.m().is_some() { 2000 } else { 0 }
+            }
+        };
+        write_header(buffer, order, type_code, srid);
+        for value in [Some(self.x()), Some(self.y()), self.z(), self.m()]
+            .into_iter()
+            .flatten()
+        {
+            write_f64(buffer, order, value);
+        }
+    }
+}
+
+impl ToWkb for LineString {
+    fn write_wkb(&self, buffer: &mut Vec<u8>, order: ByteOrder, srid: Option<u32>) {
+        write_header(buffer, order, 2, srid);
+        write_coordinates(buffer, order, self);
+    }
+}
+
+impl ToWkb for Polygon {
+    fn write_wkb(&self, buffer: &mut Vec<u8>, order: ByteOrder, srid: Option<u32>) {
+        write_header(buffer, order, 3, srid);
+        write_u32(buffer, order, self.len() as u32);
+        for ring in self.iter() {
+            write_coordinates(buffer, order, ring);
+        }
+    }
+}
+
+impl ToWkb for MultiPoint {
+    fn write_wkb(&self, buffer: &mut Vec<u8>, order: ByteOrder, srid: Option<u32>) {
+        write_header(buffer, order, 4, srid);
+        write_u32(buffer, order, self.len() as u32);
+        for point in self.iter() {
+            point.write_wkb(buffer, order, None);
+        }
+    }
+}
+
+impl ToWkb for MultiLineString {
+    fn write_wkb(&self, buffer: &mut Vec<u8>, order: ByteOrder, srid: Option<u32>) {
+        write_header(buffer, order, 5, srid);
+        write_u32(buffer, order, self.len() as u32);
+        for line_string in self.iter() {
+            line_string.write_wkb(buffer, order, None);
+        }
+    }
+}
+
+impl ToWkb for MultiPolygon {
+    fn write_wkb(&self, buffer: &mut Vec<u8>, order: ByteOrder, srid: Option<u32>) {
+        write_header(buffer, order, 6, srid);
+        write_u32(buffer, order, self.len() as u32);
+        for polygon in self.iter() {
+            polygon.write_wkb(buffer, order, None);
+        }
+    }
+}
+
+impl ToWkb for Geometry {
+    fn write_wkb(&self, buffer: &mut Vec<u8>, order: ByteOrder, srid: Option<u32>) {
+        match self {
+            Geometry::Point(point) => point.write_wkb(buffer, order, srid),
+            Geometry::MultiPoint(multi_point) => multi_point.write_wkb(buffer, order, srid),
+            Geometry::LineString(line_string) => line_string.write_wkb(buffer, order, srid),
+            Geometry::MultiLineString(multi_line_string) => {
+                multi_line_string.write_wkb(buffer, order, srid)
+            }
+            Geometry::Polygon(polygon) => polygon.write_wkb(buffer, order, srid),
+            Geometry::MultiPolygon(multi_polygon) => multi_polygon.write_wkb(buffer, order, srid),
+        }
+    }
+}
+
+/// A cursor over WKB or EWKB bytes.
+struct Reader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn error(&self, message: &str) -> GeometryError {
+        GeometryError::InvalidWkb(format!("{} at byte {}", message, self.position))
+    }
+
+    fn take<const N: usize>(&mut self) -> Result<[u8; N], GeometryError> {
+        let bytes = self
+            .bytes
+            .get(self.position..self.position + N)
+            .ok_or_else(|| self.error("unexpected end of input"))?;
+        self.position += N;
+        Ok(bytes.try_into().unwrap())
+    }
+
+    fn byte_order(&mut self) -> Result<ByteOrder, GeometryError> {
+        match self.take::<1>()? {
+            [0] => Ok(ByteOrder::BigEndian),
+            [1] => Ok(ByteOrder::LittleEndian),
+            _ => Err(self.error("invalid byte order")),
+        }
+    }
+
+    fn u32(&mut self, order: ByteOrder) -> Result<u32, GeometryError> {
+        let bytes = self.take()?;
+        Ok(match order {
+            ByteOrder::BigEndian => u32::from_be_bytes(bytes),
+            ByteOrder::LittleEndian => u32::from_le_bytes(bytes),
+        })
+    }
+
+    fn f64(&mut self, order: ByteOrder) -> Result<f64, GeometryError> {
+        let bytes = self.take()?;
+        Ok(match order {
+            ByteOrder::BigEndian => f64::from_be_bytes(bytes),
+            ByteOrder::LittleEndian => f64::from_le_bytes(bytes),
+        })
+    }
+
+    fn coordinates(&mut self, order: ByteOrder) -> Result<Vec<[f64; 2]>, GeometryError> {
+        let count = self.u32(order)?;
+        (0..count)
+            .map(|_| Ok([self.f64(order)?, self.f64(order)?]))
+            .collect()
+    }
+
+    /// Read one geometry, returning it with its SRID if it has one.
+    ///
+    /// Members of a multi-geometry are read with the `expected` kind, and rejected as soon
+    /// as their header names any other kind, so nested multi-geometries never recurse.
+    fn geometry(
+        &mut self,
+        expected: Option<u32>,
+    ) -> Result<(Geometry, Option<u32>), GeometryError> {
+        let order = self.byte_order()?;
+        let type_code = self.u32(order)?;
+        let srid = if type_code & EWKB_SRID_FLAG != 0 {
+            Some(self.u32(order)?)
+        } else {
+            None
+        };
+        let base = type_code & 0x0FFF_FFFF;
+        let has_z = type_code & EWKB_Z_FLAG != 0 || matches!(base / 1000, 1 | 3);
+        let has_m = type_code & EWKB_M_FLAG != 0 || matches!(base / 1000, 2 | 3);
+        let kind = base % 1000;
+        match expected {
+            Some(1) if kind != 1 => return Err(self.error("expected a point")),
+            Some(2) if kind != 2 => return Err(self.error("expected a line string")),
+            Some(3) if kind != 3 => return Err(self.error("expected a polygon")),
+            _ => {}
+        }
+        if (has_z || has_m) && kind != 1 {
+            return Err(self.error("z and m values are only supported for points"));
+        }
+        let members = |reader: &mut Self, kind: u32| -> Result<Vec<Geometry>, GeometryError> {
+            let count = reader.u32(order)?;
+            (0..count)
+                .map(|_| reader.geometry(Some(kind)).map(|(geometry, _)| geometry))
+                .collect()
+        };
+        let geometry = match kind {
+            1 => {
+                let mut point = Point::new(self.f64(order)?, self.f64(order)?);
+                if has_z {
+                    point = point.with_z(self.f64(order)?);
+                }
+                if has_m {
+                    point = point.with_m(self.f64(order)?);
+                }
+                Geometry::Point(point)
+            }
+            2 => Geometry::LineString(LineString::new(self.coordinates(order)?)?),
+            3 => {
+                let count = self.u32(order)?;
+                let rings = (0..count)
+                    .map(|_| self.coordinates(order))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Geometry::Polygon(Polygon::new(rings)?)
+            }
+            4 => Geometry::MultiPoint(MultiPoint::new(
+                members(self, 1)?
+                    .into_iter()
+                    .map(|member| match member {
+                        Geometry::Point(point) => Ok(point),
+                        _ => Err(self.error("expected a point")),
+                    })
+                    .collect::<Result<_, _>>()?,
+            )),
+            5 => Geometry::MultiLineString(MultiLineString::new(
+                members(self, 2)?
+                    .into_iter()
+                    .map(|member| match member {
+                        Geometry::LineString(line_string) => Ok(line_string),
+                        _ => Err(self.error("expected a line string")),
+                    })
+                    .collect::<Result<_, _>>()?,
+            )),
+            6 => Geometry::MultiPolygon(MultiPolygon::new(
+                members(self, 3)?
+                    .into_iter()
+                    .map(|member| match member {
+                        Geometry::Polygon(polygon) => Ok(polygon),
+                        _ => Err(self.error("expected a polygon")),
+                    })
+                    .collect::<Result<_, _>>()?,
+            )),
+            _ => return Err(self.error(&format!("unsupported geometry type {}", type_code))),
+        };
+        Ok((geometry, srid))
+    }
+}
+
+/// Parse a geometry from PostGIS Extended Well-Known Binary, returning it with its SRID,
+/// if it has one.
+///
+/// Plain WKB, including ISO type codes for `Point`s with z or m values, is also accepted
+/// and has no SRID.
+///
+/// # Examples:
+///
+/// ```
+/// use auto_gis_with_rust::geometry::Geometry;
+/// use auto_gis_with_rust::line_string::LineString;
+/// use auto_gis_with_rust::point::Point;
+/// use auto_gis_with_rust::wkb::{read_ewkb, ByteOrder, ToWkb};
+///
+/// let ewkb = [
+///     1, 1, 0, 0, 32, 230, 16, 0, 0,
+///     0, 0, 0, 0, 0, 0, 240, 63,
+///     0, 0, 0, 0, 0, 0, 0, 64,
+/// ];
+///
+/// assert_eq!(read_ewkb(&ewkb).unwrap(), (Geometry::Point(Point::new(1, 2)), Some(4326)));
+///
+/// let line_string = Geometry::from(LineString::new(vec![[0, 0], [1, 1]]).unwrap());
+/// let wkb = line_string.to_wkb(ByteOrder::BigEndian);
+///
+/// assert_eq!(read_ewkb(&wkb).unwrap(), (line_string, None));
+/// assert!(read_ewkb(&wkb[..10]).is_err());
+/// ```
+///
+/// Multi-geometries may only hold their own kind of member, so a `MultiPolygon` nested in
+/// another is rejected.
+///
+/// ```
+/// use auto_gis_with_rust::wkb::read_ewkb;
+///
+/// let nested = [
+///     1, 6, 0, 0, 0, 1, 0, 0, 0,
+///     1, 6, 0, 0, 0, 0, 0, 0, 0,
+/// ];
+///
+/// assert!(read_ewkb(&nested).is_err());
+/// assert!(read_ewkb(&nested[9..]).is_ok());
+///
+/// let deeply_nested: Vec<u8> = [1, 6, 0, 0, 0, 1, 0, 0, 0].repeat(1_000_000);
+///
+/// assert!(read_ewkb(&deeply_nested).is_err());
+/// ```
+pub fn read_ewkb(bytes: &[u8]) -> Result<(Geometry, Option<u32>), GeometryError> {
+    let mut reader = Reader { bytes, position: 0 };
+    let result = reader.geometry(None)?;
+    if reader.position != bytes.len() {
+        return Err(reader.error("unexpected trailing bytes"));
+    }
+    Ok(result)
+}
@@ -0,0 +1,359 @@
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::GeometryError;
+use crate::geometry::Geometry;
+use crate::line_string::{LineString, MultiLineString};
+use crate::point::{MultiPoint, Point};
+use crate::polygon::{MultiPolygon, Polygon};
+use crate::traits::{GeometryType, MapCoordinates};
+
+/// A recursive descent parser over a single WKT string.
+struct Parser<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(input: &'a str) -> Self {
+        Parser { input, position: 0 }
+    }
+
+    fn error(&self, message: &str) -> GeometryError {
+        GeometryError::InvalidWkt(format!("{} at position {}", message, self.position))
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = &self.input[self.position..];
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.skip_whitespace();
+        self.input[self.position..].chars().next()
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), GeometryError> {
+        if self.peek() == Some(expected) {
+            self.position += expected.len_utf8();
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected '{}'", expected)))
+        }
+    }
+
+    fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> &'a str {
+        self.skip_whitespace();
+        let rest = &self.input[self.position..];
+        let length = rest.find(|c| !predicate(c)).unwrap_or(rest.len());
+        self.position += length;
+        &rest[..length]
+    }
+
+    fn word(&mut self) -> String {
+        self.take_while(|c| c.is_ascii_alphabetic())
+            .to_ascii_uppercase()
+    }
+
+    /// Consume `EMPTY` if it comes next, returning whether it did.
+    fn empty(&mut self) -> bool {
+        let start = self.position;
+        if self.word() == "EMPTY" {
+            true
+        } else {
+            self.position = start;
+            false
+        }
+    }
+
+    fn number(&mut self) -> Result<f64, GeometryError> {
+        let start = self.position;
+        self.take_while(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '+'))
+            .parse()
+            .map_err(|_| {
+                self.position = start;
+                self.error("expected a number")
+            })
+    }
+
+    /// Consume an optional `Z`, `M` or `ZM` dimension marker, returning whether z and m
+    /// values follow.
+    fn dimensions(&mut self) -> Result<(bool, bool), GeometryError> {
+        let start = self.position;
+        match self.word().as_str() {
+            "Z" => Ok((true, false)),
+            "M" => Ok((false, true)),
+            "ZM" => Ok((true, true)),
+            "" | "EMPTY" => {
+                self.position = start;
+                Ok((false, false))
+            }
+            _ => {
+                self.position = start;
+                Err(self.error("expected Z, M or ZM"))
+            }
+        }
+    }
+
+    fn coordinate(&mut self) -> Result<[f64; 2], GeometryError> {
+        Ok([self.number()?, self.number()?])
+    }
+
+    /// Parse a parenthesized, comma-separated list of `item`s, or `EMPTY`.
+    fn list<T>(
+        &mut self,
+        mut item: impl FnMut(&mut Self) -> Result<T, GeometryError>,
+    ) -> Result<Vec<T>, GeometryError> {
+        if self.empty() {
+            return Ok(Vec::new());
+        }
+        self.expect('(')?;
+        let mut items = Vec::new();
+        if self.peek() != Some(')') {
+            items.push(item(self)?);
+            while self.peek() == Some(',') {
+                self.expect(',')?;
+                items.push(item(self)?);
+            }
+        }
+        self.expect(')')?;
+        Ok(items)
+    }
+
+    fn coordinates(&mut self) -> Result<Vec<[f64; 2]>, GeometryError> {
+        self.list(Self::coordinate)
+    }
+
+    /// A member of a `MULTIPOINT`, which may or may not be wrapped in parentheses.
+    fn multi_point_member(&mut self) -> Result<[f64; 2], GeometryError> {
+        if self.peek() == Some('(') {
+            self.expect('(')?;
+            let coordinate = self.coordinate()?;
+            self.expect(')')?;
+            Ok(coordinate)
+        } else {
+            self.coordinate()
+        }
+    }
+
+    fn geometry(&mut self) -> Result<Geometry, GeometryError> {
+        let tag = self.word();
+        let (has_z, has_m) = self.dimensions()?;
+        if (has_z || has_m) && tag != "POINT" {
+            return Err(self.error(&format!("z and m values are not supported for {}", tag)));
+        }
+        let geometry = match tag.as_str() {
+            "POINT" => {
+                self.expect('(')?;
+                let [x, y] = self.coordinate()?;
+                let mut point = Point::new(x, y);
+                if has_z {
+                    point = point.with_z(self.number()?);
+                }
+                if has_m {
+                    point = point.with_m(self.number()?);
+                }
+                self.expect(')')?;
+                Geometry::Point(point)
+            }
+            "MULTIPOINT" => {
+                Geometry::MultiPoint(MultiPoint::from(self.list(Self::multi_point_member)?))
+            }
+            "LINESTRING" => Geometry::LineString(LineString::new(self.coordinates()?)?),
+            "MULTILINESTRING" => Geometry::MultiLineString(MultiLineString::new(
+                self.list(|parser| LineString::new(parser.coordinates()?))?,
+            )),
+            "POLYGON" => Geometry::Polygon(Polygon::new(self.list(Self::coordinates)?)?),
+            "MULTIPOLYGON" => Geometry::MultiPolygon(MultiPolygon::new(
+                self.list(|parser| Polygon::new(parser.list(Self::coordinates)?))?,
+            )),
+            "" => return Err(self.error("expected a geometry type")),
+            _ => return Err(self.error(&format!("unsupported geometry type {}", tag))),
+        };
+        if self.peek().is_some() {
+            return Err(self.error("unexpected trailing input"));
+        }
+        Ok(geometry)
+    }
+}
+
+impl FromStr for Geometry {
+    type Err = GeometryError;
+
+    /// Parse a geometry from its Well-Known Text representation.
+    ///
+    /// Geometry type names are case-insensitive, and `MULTIPOINT` members may be written
+    /// with or without parentheses. Each concrete geometry type can be parsed the same
+    /// way, failing if the text describes a different type. Every type can also be parsed
+    /// through `TryFrom<&str>`, for code that is generic over `TryFrom`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::geometry::Geometry;
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// assert_eq!("POINT (0 1)".parse::<Point>().unwrap(), Point::new(0, 1));
+    /// assert_eq!("POINT Z (0 1 2)".parse::<Point>().unwrap(), Point::new(0, 1).with_z(2.));
+    /// assert_eq!("POINT ZM (0 1 2 3)".parse::<Point>().unwrap().m(), Some(3.));
+    /// assert!("MULTIPOINT (0 1)".parse::<Point>().is_err());
+    ///
+    /// let geometry: Geometry = "polygon ((0 0, 1 0, 1 1, 0 0))".parse().unwrap();
+    ///
+    /// assert_eq!(geometry.to_string(), "POLYGON ((0 0, 1 0, 1 1, 0 0))");
+    /// assert!("POLYGON ((0 0, 1 0)".parse::<Geometry>().is_err());
+    /// assert!("CIRCLE (0 0, 1)".parse::<Geometry>().is_err());
+    /// ```
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use auto_gis_with_rust::geometry::Geometry;
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let line_string = LineString::try_from("LINESTRING (0 0, 1 1)").unwrap();
+    ///
+    /// assert_eq!(line_string, "LINESTRING (0 0, 1 1)".parse().unwrap());
+    /// assert!(LineString::try_from("POINT (0 0)").is_err());
+    /// assert!(Geometry::try_from("POINT (0 0").is_err());
+    /// ```
+    fn from_str(wkt: &str) -> Result<Self, Self::Err> {
+        Parser::new(wkt).geometry()
+    }
+}
+
+macro_rules! implement_from_str {
+    ($($variant:ident),*) => {
+        $(
+            impl FromStr for $variant {
+                type Err = GeometryError;
+
+                fn from_str(wkt: &str) -> Result<Self, Self::Err> {
+                    match wkt.parse()? {
+                        Geometry::$variant(geometry) => Ok(geometry),
+                        other => Err(GeometryError::InvalidWkt(format!(
+                            "expected {}, found {}",
+                            stringify!($variant),
+                            other.geometry_type(),
+                        ))),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+implement_from_str!(
+    Point,
+    MultiPoint,
+    LineString,
+    MultiLineString,
+    Polygon,
+    MultiPolygon
+);
+
+macro_rules! implement_try_from_str {
+    ($($type:ident),*) => {
+        $(
+            impl TryFrom<&str> for $type {
+                type Error = GeometryError;
+
+                fn try_from(wkt: &str) -> Result<Self, Self::Error> {
+                    wkt.parse()
+                }
+            }
+        )*
+    };
+}
+
+implement_try_from_str!(
+    Geometry,
+    Point,
+    MultiPoint,
+    LineString,
+    MultiLineString,
+    Polygon,
+    MultiPolygon
+);
+
+/// Serializes a geometry as Well-Known Text.
+///
+/// The text is the same as the geometry's `Display` output, which can be parsed back with
+/// `FromStr`.
+pub trait ToWkt: fmt::Display + MapCoordinates {
+    /// This geometry as WKT.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::geometry::Geometry;
+    /// use auto_gis_with_rust::line_string::LineString;
+    /// use auto_gis_with_rust::wkt::ToWkt;
+    ///
+    /// let line_string = LineString::new(vec![[0, 0], [1, 2]]).unwrap();
+    ///
+    /// assert_eq!(line_string.to_wkt(), "LINESTRING (0 0, 1 2)");
+    /// assert_eq!(Geometry::from(line_string).to_wkt(), "LINESTRING (0 0, 1 2)");
+    /// ```
+    fn to_wkt(&self) -> String {
+        self.to_string()
+    }
+
+    /// This geometry as WKT, with every value rounded to `decimals` decimal places as
+    /// [`round_coordinates`](MapCoordinates::round_coordinates) does. Trailing zeros are
+    /// not written.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::Point;
+    /// use auto_gis_with_rust::polygon::Polygon;
+    /// use auto_gis_with_rust::wkt::ToWkt;
+    ///
+    /// let polygon = Polygon::new(vec![vec![[0., 0.], [1. / 3., 0.], [0., 2. / 3.]]]).unwrap();
+    /// let point = Point::new(0.12345, 1.5).with_z(2. / 3.);
+    ///
+    /// assert_eq!(
+    ///     polygon.to_wkt_with_precision(2),
+    ///     "POLYGON ((0 0, 0.33 0, 0 0.67, 0 0))",
+    /// );
+    /// assert_eq!(point.to_wkt_with_precision(3), "POINT Z (0.123 1.5 0.667)");
+    /// ```
+    fn to_wkt_with_precision(&self, decimals: u32) -> String {
+        self.round_coordinates(decimals).to_string()
+    }
+}
+
+impl ToWkt for Point {
+    fn to_wkt_with_precision(&self, decimals: u32) -> String {
+        let factor = 10f64.powi(decimals as i32);
+        let round = |value: f64| (value * factor).round() / factor;
+        let mut point = self.round_coordinates(decimals);
+        if let Some(z) = self.z() {
+            point = point.with_z(round(z));
+        }
+        if let Some(m) = self.m() {
+            point = point.with_m(round(m));
+        }
+        point.to_string()
+    }
+}
+
+impl ToWkt for MultiPoint {}
+
+impl ToWkt for LineString {}
+
+impl ToWkt for MultiLineString {}
+
+impl ToWkt for Polygon {}
+
+impl ToWkt for MultiPolygon {}
+
+impl ToWkt for Geometry {
+    fn to_wkt_with_precision(&self, decimals: u32) -> String {
+        match self {
+            Geometry::Point(point) => point.to_wkt_with_precision(decimals),
+            _ => self.round_coordinates(decimals).to_string(),
+        }
+    }
+}